@@ -0,0 +1,86 @@
+#![deny(clippy::all)]
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::{env, fs, io};
+use heimdall_errors::{implement_error_with_kind, implement_error_with_source};
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    IO(io::ErrorKind),
+}
+
+#[derive(Debug)]
+pub struct StructError {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl StructError {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for StructError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.message, f)
+    }
+}
+
+impl Error for StructError {}
+
+implement_error_with_kind!(StructError, io::Error, ErrorKind::IO);
+
+#[derive(Debug)]
+pub struct SourceError {
+    kind: ErrorKind,
+    message: String,
+    source: Option<Box<dyn Error>>,
+}
+
+impl SourceError {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn source(&self) -> Option<&dyn Error> {
+        self.source.as_deref()
+    }
+}
+
+impl Display for SourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.message, f)
+    }
+}
+
+implement_error_with_source!(SourceError, io::Error, ErrorKind::IO);
+
+
+fn foo() -> Result<(), StructError> {
+    let mut path = env::temp_dir();
+    path.push("inexist.file.ñ");
+    let content = fs::read_to_string(path)?;
+    println!("{content}");
+
+    Ok(())
+}
+
+fn bar() -> Result<(), SourceError> {
+    let mut path = env::temp_dir();
+    path.push("inexist.file.ñ");
+    let content = fs::read_to_string(path)?;
+    println!("{content}");
+
+    Ok(())
+}
+
+fn main() {
+    let err = foo().unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::IO(io::ErrorKind::NotFound));
+
+    let err = bar().unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::IO(io::ErrorKind::NotFound));
+    assert!(err.source().is_some());
+}
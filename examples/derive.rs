@@ -0,0 +1,83 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::{env, fs, io};
+use std::env::{var, VarError};
+use std::num::ParseIntError;
+use heimdall_errors::FromErrors;
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    IO,
+    Var,
+    Parse,
+}
+
+#[derive(Debug, FromErrors)]
+#[from_errors(io::Error => ErrorKind::IO, VarError => ErrorKind::Var)]
+pub struct StructError {
+    #[from(ParseIntError => ErrorKind::Parse)]
+    kind: ErrorKind,
+    message: String,
+}
+
+impl StructError {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for StructError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.message, f)
+    }
+}
+
+impl Error for StructError {}
+
+
+fn foo() -> Result<(), StructError> {
+    let mut path = env::temp_dir();
+    path.push("inexist.file.ñ");
+    let content = fs::read_to_string(path)?;
+    println!("{content}");
+
+    Ok(())
+}
+
+fn bar() -> Result<(), StructError> {
+    let value = var("INEXIST_ENV_VAR")?;
+    println!("{value}");
+
+    Ok(())
+}
+
+fn baz() -> Result<(), StructError> {
+    let value: u8 = "heimdall".parse()?;
+    println!("{value}");
+
+    Ok(())
+}
+
+fn main() {
+    let err = foo().unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::IO);
+
+    let err = bar().unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::Var);
+
+    let err = baz().unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::Parse);
+    assert_eq!(err.message(), "invalid digit found in string");
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn works() {
+        super::main();
+    }
+}
@@ -0,0 +1,80 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::num::ParseIntError;
+use core::str::Utf8Error;
+use heimdall_errors::{
+    implement_error, implement_error_display, implement_in_error_in_struct,
+    implement_std_error, implement_string_error_in_enum,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorKind {
+    Parse,
+    Utf8,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Parse => write!(f, "Parse"),
+            ErrorKind::Utf8 => write!(f, "Utf8"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StructError {
+    kind: ErrorKind,
+    message: String,
+}
+
+implement_error!(StructError, ParseIntError, ErrorKind::Parse);
+implement_error!(StructError, Utf8Error, ErrorKind::Utf8);
+implement_error_display!(StructError);
+
+#[derive(Debug)]
+pub struct SourceError {
+    kind: ErrorKind,
+    message: String,
+    source: Option<Box<dyn Error>>,
+}
+
+impl SourceError {
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for SourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.message, f)
+    }
+}
+
+implement_in_error_in_struct!(SourceError, ParseIntError, ErrorKind::Parse);
+implement_std_error!(SourceError);
+
+#[derive(Debug, PartialEq)]
+pub enum EnumError {
+    Parse(String),
+}
+
+implement_string_error_in_enum!(EnumError, ParseIntError, EnumError::Parse);
+
+pub fn parse(value: &str) -> Result<u8, StructError> {
+    Ok(value.parse()?)
+}
+
+pub fn parse_with_source(value: &str) -> Result<u8, SourceError> {
+    Ok(value.parse()?)
+}
+
+pub fn parse_in_enum(value: &str) -> Result<u8, EnumError> {
+    Ok(value.parse()?)
+}
@@ -30,6 +30,7 @@ impl StructError {
         &self.message
     }
 
+    #[allow(clippy::borrowed_box)]
     pub fn source(&self) -> Option<&Box<dyn Error>> {
         self.source.as_ref()
     }
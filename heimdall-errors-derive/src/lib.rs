@@ -0,0 +1,152 @@
+//! Derive macros for [heimdall_errors]
+//!
+//! [heimdall_errors]:https://crates.io/crates/heimdall_errors
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Attribute, Data, DataStruct, DeriveInput, Fields, Path, Token};
+
+/// A `$error_type => $error_kind` entry of a `from` or `from_errors` attribute.
+struct Mapping {
+    error_type: Path,
+    error_kind: Path,
+}
+
+impl Parse for Mapping {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let error_type = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let error_kind = input.parse()?;
+
+        Ok(Self { error_type, error_kind })
+    }
+}
+
+/// Derive the [`From`] trait for an struct with kind and `message` attributes.
+///
+/// The mappings are declared on the kind field, or on the struct itself, one per `#[from(...)]`
+/// attribute, or as a list in a `#[from_errors(...)]` attribute. The field carrying the mappings
+/// is filled with the kind, and the `message` field with the message of the error; without
+/// mappings on a field, the kind field is `kind`. Each mapping generates the same code as
+/// `heimdall_errors::implement_error!`.
+///
+/// # Example
+/// ```ignore
+/// use heimdall_errors::FromErrors;
+/// use std::env::VarError;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+/// }
+///
+/// #[derive(FromErrors)]
+/// #[from_errors(io::Error => ErrorKind::IO)]
+/// pub struct StructError {
+///     #[from(VarError => ErrorKind::Var)]
+///     kind: ErrorKind,
+///     message: String,
+/// }
+/// ```
+#[proc_macro_derive(FromErrors, attributes(from, from_errors))]
+pub fn derive_from_errors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => &fields.named,
+        Data::Struct(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "FromErrors can only be derived for structs with named fields",
+            ))
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "FromErrors can only be derived for structs",
+            ))
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "FromErrors can not be derived for generic structs",
+        ));
+    }
+
+    let struct_error = &input.ident;
+    let mut all_mappings = mappings(&input.attrs)?;
+    let mut kind_field: Option<&Ident> = None;
+
+    for field in fields {
+        let field_mappings = mappings(&field.attrs)?;
+        if field_mappings.is_empty() {
+            continue;
+        }
+
+        if kind_field.is_some() {
+            let attr = field.attrs.iter().find(|attr| is_mapping(attr)).unwrap();
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[from(...)] attributes must be on a single kind field",
+            ));
+        }
+
+        kind_field = field.ident.as_ref();
+        all_mappings.extend(field_mappings);
+    }
+
+    if all_mappings.is_empty() {
+        return Err(syn::Error::new_spanned(
+            struct_error,
+            "FromErrors requires at least one #[from(...)] or #[from_errors(...)] attribute",
+        ));
+    }
+
+    let kind_field = match kind_field {
+        Some(kind_field) => kind_field.clone(),
+        None => Ident::new("kind", Span::call_site()),
+    };
+
+    let impls = all_mappings.iter().map(|Mapping { error_type, error_kind }| {
+        quote! {
+            ::heimdall_errors::implement_error!(
+                #struct_error { kind: #kind_field, message: message },
+                #error_type,
+                #error_kind
+            );
+        }
+    });
+
+    Ok(quote! { #(#impls)* })
+}
+
+fn is_mapping(attr: &Attribute) -> bool {
+    attr.path().is_ident("from") || attr.path().is_ident("from_errors")
+}
+
+fn mappings(attrs: &[Attribute]) -> syn::Result<Vec<Mapping>> {
+    let mut mappings = Vec::new();
+
+    for attr in attrs {
+        if attr.path().is_ident("from") {
+            mappings.push(attr.parse_args::<Mapping>()?);
+        } else if attr.path().is_ident("from_errors") {
+            let list = attr.parse_args_with(Punctuated::<Mapping, Token![,]>::parse_terminated)?;
+            mappings.extend(list);
+        }
+    }
+
+    Ok(mappings)
+}
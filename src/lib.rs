@@ -1,7 +1,235 @@
 //! Macros for auto impl [From<T>] for errors
+//!
+//! # Features
+//! - `std` (enabled by default): disable it to use the crate in `no_std` environments with
+//!   [alloc]. The generated code then refers to [alloc] and [core] items instead of [std] ones.
+//! - `derive`: enables the [`FromErrors`] derive macro.
+//! - `serde`: enables the `implement_error_serialize!` macro.
+//! - `tracing`: enables the `implement_error_logged!` macro, which logs with [tracing].
+//! - `metrics`: enables the `implement_error_metered!` macro, which counts with [metrics].
+//!   It depends on the [metrics] crate, which requires `std`, so it enables the `std` feature.
+//! - `nightly`: enables the `implement_never!` macro, which requires a nightly compiler and the
+//!   `never_type` feature in the calling crate.
+//! - `tonic`: enables the `implement_tonic_status!` macro, which converts errors into [tonic]
+//!   statuses. It requires `std`, so it enables the `std` feature.
+//! - `nix`: enables the `implement_error_from_nix!` macro, which keeps the errno of [nix]
+//!   errors. It requires `std`, so it enables the `std` feature.
+//!
+//! # MSRV
+//! The minimum supported Rust version is 1.81, the first release with [`core::error::Error`],
+//! which the generated code uses to support `no_std`.
+//!
+//! [tracing]:https://crates.io/crates/tracing
+//! [metrics]:https://crates.io/crates/metrics
+//! [tonic]:https://crates.io/crates/tonic
+//! [nix]:https://crates.io/crates/nix
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::borrow::Cow;
+    pub use alloc::boxed::Box;
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+    pub use core::convert::{Infallible, TryFrom};
+    pub use core::error::Error;
+    pub use core::fmt;
+    pub use core::panic::Location;
+    pub use core::result::Result;
+
+    #[cfg(feature = "std")]
+    pub use std::backtrace::Backtrace;
+    #[cfg(feature = "std")]
+    pub use std::io;
+    #[cfg(feature = "std")]
+    pub use std::process::{ExitCode, Termination};
+    #[cfg(feature = "std")]
+    pub use std::sync::PoisonError;
+    #[cfg(feature = "std")]
+    pub use std::time::SystemTime;
+
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+
+    #[cfg(feature = "metrics")]
+    pub use metrics;
+
+    #[cfg(feature = "tonic")]
+    pub use tonic;
+
+    #[cfg(feature = "nix")]
+    pub use nix;
+
+    /// Wrapper used by `implement_error!` to access the `kind` and `message` fields, named after
+    /// the requirement so it shows up in the error when a field is missing.
+    pub struct ImplementErrorRequiresFieldsKindAndMessage<'a, T>(&'a T);
+
+    impl<'a, T> ImplementErrorRequiresFieldsKindAndMessage<'a, T> {
+        pub const fn new(error: &'a T) -> Self {
+            Self(error)
+        }
+    }
+
+    impl<T> core::ops::Deref for ImplementErrorRequiresFieldsKindAndMessage<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.0
+        }
+    }
+
+    /// Gives a closure taking the error by reference its signature, capturing or not.
+    pub fn by_ref<E, R, F: Fn(&E) -> R>(f: F) -> F {
+        f
+    }
+
+    /// Gives a closure taking the error by value its signature, capturing or not.
+    pub fn by_value<E, R, F: FnOnce(E) -> R>(f: F) -> F {
+        f
+    }
+
+    /// Last segment of a stringified path, so `crate :: StructError` becomes `StructError`.
+    pub const fn type_name(path: &'static str) -> &'static str {
+        let bytes = path.as_bytes();
+        let mut end = 0;
+        while end < bytes.len() && bytes[end] != b'<' {
+            end += 1;
+        }
+        while end > 0 && bytes[end - 1] == b' ' {
+            end -= 1;
+        }
+        let mut start = end;
+        while start > 0 && bytes[start - 1] != b':' && bytes[start - 1] != b' ' {
+            start -= 1;
+        }
+        let (_, name) = bytes.split_at(start);
+        let (name, _) = name.split_at(end - start);
+        match core::str::from_utf8(name) {
+            Ok(name) => name,
+            Err(_) => path,
+        }
+    }
+}
+
+/// Derive the [`From`] trait for an struct with kind structure.
+///
+/// Available with the `derive` feature. Every `#[from(...)]` attribute, or entry of a
+/// `#[from_errors(...)]` attribute, generates the same code as [`implement_error!`].
+///
+/// The attributes go on the kind field, or on the struct itself. The field carrying them is
+/// filled with the kind, so it can have any name; otherwise the kind field is `kind`. The
+/// message always goes to the `message` field.
+///
+/// # Example
+/// ```
+/// use heimdall_errors::FromErrors;
+/// use std::env::VarError;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+/// }
+///
+/// #[derive(FromErrors)]
+/// #[from_errors(io::Error => ErrorKind::IO, VarError => ErrorKind::Var)]
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.kind, ErrorKind::Var);
+/// ```
+///
+/// With the mappings on the kind field:
+/// ```
+/// use heimdall_errors::FromErrors;
+/// use std::env::VarError;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+/// }
+///
+/// #[derive(FromErrors)]
+/// pub struct StructError {
+///     #[from(io::Error => ErrorKind::IO)]
+///     #[from(VarError => ErrorKind::Var)]
+///     category: ErrorKind,
+///     message: String,
+/// }
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.category, ErrorKind::Var);
+/// assert_eq!(err.message, VarError::NotPresent.to_string());
+/// ```
+#[cfg(feature = "derive")]
+pub use heimdall_errors_derive::FromErrors;
+
+/// Build an error from its kind and message, independently of the layout of the error.
+///
+/// Implement it once for an error to use [`implement_error_via_trait!`], which calls
+/// [`HeimdallError::from_parts`] instead of naming the fields of the struct.
+///
+/// # Example
+/// ```
+/// use heimdall_errors::HeimdallError;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     category: ErrorKind,
+///     detail: String,
+/// }
+///
+/// impl HeimdallError for StructError {
+///     type Kind = ErrorKind;
+///
+///     fn from_parts(kind: ErrorKind, message: String) -> Self {
+///         Self {
+///             category: kind,
+///             detail: message,
+///         }
+///     }
+/// }
+///
+/// let err = StructError::from_parts(ErrorKind::IO, "oh no!".to_string());
+/// assert_eq!(err.detail, "oh no!");
+/// ```
+pub trait HeimdallError: Sized {
+    /// The kind of the error.
+    type Kind;
+
+    /// Build the error from its kind and message.
+    fn from_parts(kind: Self::Kind, message: alloc::string::String) -> Self;
+}
+
+/// Who an error can be shown to, set by [`implement_error_visibility!`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// The error may expose implementation details, and must not be shown to users.
+    Internal,
+    /// The error is safe to show to users.
+    Public,
+}
 
 /// Implement the [`From`] trait for an struct with kind structure
 ///
+/// The struct must have a `kind` attribute and a `message: String` attribute. An invocation
+/// that does not match the params fails with a message describing the expected ones.
+///
 /// # Params
 /// ```ignore
 /// implement_in_error_in_struct($struct_error, $error_type, $error_kind);
@@ -43,23 +271,184 @@
 ///     }
 /// }
 /// ```
+///
+/// # Custom field names
+/// A struct without `kind` and `message` fields fails to compile with
+/// ``no field `kind` on type `ImplementErrorRequiresFieldsKindAndMessage<..>` `` at the macro call.
+/// If the fields of the struct are named differently, name them after the struct:
+/// ```
+/// use heimdall_errors::implement_error;
+/// use std::env::VarError;
+///
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     category: ErrorKind,
+///     detail: String,
+/// }
+///
+/// // Implement From<VarError> for StructError, filling `category` and `detail`.
+/// implement_error!(StructError { kind: category, message: detail }, VarError, ErrorKind::Var);
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.detail, VarError::NotPresent.to_string());
+/// ```
+///
+/// # Module paths
+/// The struct can be referenced by its path, without importing it at the call site:
+/// ```
+/// use heimdall_errors::implement_error;
+///
+/// mod errors {
+///     pub enum ErrorKind {
+///         Var,
+///     }
+///
+///     pub struct StructError {
+///         pub kind: ErrorKind,
+///         pub message: String,
+///     }
+/// }
+///
+/// // Implement From<VarError> for errors::StructError.
+/// implement_error!(errors::StructError, std::env::VarError, errors::ErrorKind::Var);
+///
+/// let err = errors::StructError::from(std::env::VarError::NotPresent);
+/// assert!(matches!(err.kind, errors::ErrorKind::Var));
+/// ```
+///
+/// # Shared errors
+/// `impl From<VarError> for Arc<StructError>` cannot be generated: the orphan rule (E0117) forbids
+/// implementing a foreign trait for the foreign type `Arc`, and the same applies to `Rc`. Convert
+/// in two steps instead, since `Arc<T>` and `Rc<T>` already implement `From<T>`:
+/// ```
+/// use heimdall_errors::implement_error;
+/// use std::env::VarError;
+/// use std::rc::Rc;
+/// use std::sync::Arc;
+///
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// implement_error!(StructError, VarError, ErrorKind::Var);
+///
+/// fn read() -> Result<String, Arc<StructError>> {
+///     let value = std::env::var("HEIMDALL_UNSET_VARIABLE").map_err(StructError::from)?;
+///     Ok(value)
+/// }
+///
+/// let err = read().unwrap_err();
+/// assert!(matches!(err.kind, ErrorKind::Var));
+/// assert_eq!(Arc::strong_count(&err), 1);
+///
+/// // The same for single-threaded code.
+/// let err: Rc<StructError> = StructError::from(VarError::NotPresent).into();
+/// assert!(matches!(err.kind, ErrorKind::Var));
+/// ```
 #[macro_export]
 macro_rules! implement_error {
-    ($struct_error:ident, $error_type: path, $error_kind: path) => {
+    ($struct_error:path { kind: $kind_field:ident, message: $message_field:ident }, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    $kind_field: $error_kind,
+                    $message_field: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        const _: fn(&$struct_error) = |error| {
+            let error = $crate::__private::ImplementErrorRequiresFieldsKindAndMessage::new(error);
+            let _ = (&error.kind, &error.message);
+        };
+
         impl From<$error_type> for $struct_error {
             fn from(error: $error_type) -> $struct_error {
                 $struct_error {
                     kind: $error_kind,
-                    message: error.to_string(),
+                    message: $crate::__private::ToString::to_string(&error),
                 }
             }
         }
     };
+    ($($tokens:tt)*) => {
+        compile_error!(
+            "implement_error! expects three arguments: \
+            implement_error!($struct_error, $error_type, $error_kind) or \
+            implement_error!($struct_error { kind: $kind_field, message: $message_field }, $error_type, $error_kind)"
+        );
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, for many error types at once.
+///
+/// Each entry is expanded into an [`implement_error!`] invocation.
+///
+/// # Params
+/// ```ignore
+/// implement_errors!($struct_error, [($error_type, $error_kind), ...]);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_errors;
+/// use std::env::VarError;
+/// use std::io;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+///     Parse,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error>, From<VarError> and From<ParseIntError> for StructError.
+/// implement_errors!(StructError, [
+///     (io::Error, ErrorKind::IO),
+///     (VarError, ErrorKind::Var),
+///     (ParseIntError, ErrorKind::Parse),
+/// ]);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.kind, ErrorKind::IO);
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.kind, ErrorKind::Var);
+///
+/// let err = StructError::from("heimdall".parse::<u8>().unwrap_err());
+/// assert_eq!(err.kind, ErrorKind::Parse);
+/// ```
+#[macro_export]
+macro_rules! implement_errors {
+    ($struct_error:path, [$(($error_type: path, $error_kind: path)),* $(,)?]) => {
+        $(
+            $crate::implement_error!($struct_error, $error_type, $error_kind);
+        )*
+    };
 }
 
 /// Implement the [`From`] trait for an struct with an specific structure, with ErrorKind,
 /// and message attributes.
 ///
+/// The error must have a `kind(&self)` method returning a [`Clone`] value or a reference to a
+/// [`Clone`] value, like `fn kind(&self) -> &K`. If the method returns an owned value, prefer
+/// [`implement_error_with_owned_kind!`]. The generated code allows the `clippy::clone_on_copy`
+/// lint, so it compiles cleanly with `#![deny(clippy::all)]` even when the kind is [`Copy`].
+///
 /// # Params
 /// ```ignore
 /// implement_in_error_in_struct($struct_error, $error_type, $error_kind);
@@ -102,12 +491,77 @@ macro_rules! implement_error {
 /// ```
 #[macro_export]
 macro_rules! implement_error_with_kind {
-    ($err:ident, $t: path, $kind: path) => {
+    ($err:path, $t: path, $kind: path) => {
         impl From<$t> for $err {
+            #[allow(clippy::clone_on_copy)]
             fn from(error: $t) -> $err {
                 $err {
                     kind: $kind(error.kind().clone()),
-                    message: error.to_string(),
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with an specific structure, with ErrorKind,
+/// and message attributes, without cloning the kind of the error.
+///
+/// The error must have a `kind(&self)` method returning an owned value, like
+/// `fn kind(&self) -> K` of [`std::io::Error`]. If the method returns a reference, use
+/// [`implement_error_with_kind!`] instead.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_owned_kind!($err, $t, $kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_owned_kind;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO(io::ErrorKind),
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_owned_kind!(StructError, io::Error, ErrorKind::IO);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.kind, ErrorKind::IO(io::ErrorKind::NotFound));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_owned_kind!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO(err.kind()),
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_owned_kind {
+    ($err:path, $t: path, $kind: path) => {
+        impl From<$t> for $err {
+            fn from(error: $t) -> $err {
+                $err {
+                    kind: $kind(error.kind()),
+                    message: $crate::__private::ToString::to_string(&error),
                 }
             }
         }
@@ -155,9 +609,35 @@ macro_rules! implement_error_with_kind {
 /// }
 /// ```
 ///
+/// # Generic enums
+/// A generic enum is written with its parameters, optionally followed by a `where` clause:
+/// ```
+/// use heimdall_errors::implement_error_in_enum;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// pub enum EnumError<E> {
+///     Wrapped(E),
+///     Parse(ParseIntError),
+/// }
+///
+/// // Implement From<ParseIntError> for EnumError<E>.
+/// implement_error_in_enum!(EnumError<E>, ParseIntError, EnumError::Parse);
+///
+/// let err: EnumError<String> = EnumError::from("x".parse::<u8>().unwrap_err());
+/// assert!(matches!(err, EnumError::Parse(_)));
+/// ```
+///
 /// [thiserror]:https://crates.io/crates/thiserror
 #[macro_export]
 macro_rules! implement_error_in_enum {
+    ($enum_error:ident<$($param:ident),+ $(,)?>, $err_type: path, $enum_variant: path $(, where $($bounds:tt)+)?) => {
+        impl<$($param),+> From<$err_type> for $enum_error<$($param),+> $(where $($bounds)+)? {
+            fn from(error: $err_type) -> Self {
+                $enum_variant(error)
+            }
+        }
+    };
     ($enum_error:ident, $err_type: path, $enum_variant: path) => {
         impl From<$err_type> for $enum_error {
             fn from(error: $err_type) -> $enum_error {
@@ -202,12 +682,51 @@ macro_rules! implement_error_in_enum {
 ///     }
 /// }
 /// ```
+///
+/// # Generic enums
+/// A generic enum is written with its parameters, optionally followed by a `where` clause.
+/// The parameters are declared on the generated impl, so they must not be used as the name of
+/// the source error type.
+/// ```
+/// use heimdall_errors::implement_string_error_in_enum;
+/// use std::fmt::Display;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum EnumError<E> {
+///     Wrapped(E),
+///     IO(String),
+/// }
+///
+/// // Implement From<io::Error> for EnumError<E>, for every displayable E.
+/// implement_string_error_in_enum!(EnumError<E>, io::Error, EnumError::IO, where E: Display);
+///
+/// let err: EnumError<u8> = EnumError::from(io::Error::new(io::ErrorKind::Other, "disk full"));
+/// assert_eq!(err, EnumError::IO("disk full".to_string()));
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl<E> From<io::Error> for EnumError<E> where E: Display {
+///    fn from(err: io::Error) -> Self {
+///        EnumError::IO(err.to_string())
+///     }
+/// }
+/// ```
 #[macro_export]
 macro_rules! implement_string_error_in_enum {
+    ($enum_error:ident<$($param:ident),+ $(,)?>, $err_type: path, $enum_variant: path $(, where $($bounds:tt)+)?) => {
+        impl<$($param),+> From<$err_type> for $enum_error<$($param),+> $(where $($bounds)+)? {
+            fn from(error: $err_type) -> Self {
+                $enum_variant($crate::__private::ToString::to_string(&error))
+            }
+        }
+    };
     ($enum_error:ident, $err_type: path, $enum_variant: path) => {
         impl From<$err_type> for $enum_error {
             fn from(error: $err_type) -> $enum_error {
-                $enum_variant(error.to_string())
+                $enum_variant($crate::__private::ToString::to_string(&error))
             }
         }
     };
@@ -238,15 +757,6473 @@ macro_rules! implement_string_error_in_enum {
 /// ```
 #[macro_export]
 macro_rules! implement_in_error_in_struct {
-    ($struct_error:ident, $err_type: path, $kind: path) => {
+    ($struct_error:path, $err_type: path, $kind: path) => {
         impl From<$err_type> for $struct_error {
             fn from(err: $err_type) -> Self {
                 Self {
                     kind: $kind,
-                    message: err.to_string(),
-                    source: Some(Box::new(err)),
+                    message: $crate::__private::ToString::to_string(&err),
+                    source: Some($crate::__private::Box::new(err)),
                 }
             }
         }
     };
-}
\ No newline at end of file
+}
+/// Implement the [`From`] trait for an struct with kind, message and source attributes, where
+/// the kind is recovered from the `kind()` method of the error.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_source!($err, $t, $kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_source;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO(io::ErrorKind),
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error>>
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_source!(StructError, io::Error, ErrorKind::IO);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert!(matches!(err.kind, ErrorKind::IO(io::ErrorKind::NotFound)));
+///
+/// let source = err.source.unwrap();
+/// let source = source.downcast_ref::<io::Error>().unwrap();
+/// assert_eq!(source.kind(), io::ErrorKind::NotFound);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_source!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        let kind = ErrorKind::IO(err.kind().clone());
+///        Self {
+///            kind,
+///            message: err.to_string(),
+///            source: Some(Box::new(err)),
+///        }
+///     }
+/// }
+/// ```
+///
+/// The kind is read before the error is moved into the source.
+#[macro_export]
+macro_rules! implement_error_with_source {
+    ($err:path, $t: path, $kind: path) => {
+        impl From<$t> for $err {
+            #[allow(clippy::clone_on_copy)]
+            fn from(error: $t) -> $err {
+                let kind = $kind(error.kind().clone());
+                $err {
+                    kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    source: Some($crate::__private::Box::new(error)),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`Display`](std::fmt::Display) trait for an struct with kind and message
+/// attributes.
+///
+/// The kind is printed using its [ToString] implementation, so the kind type must implement
+/// [ToString] (usually through [`Display`](std::fmt::Display)).
+///
+/// # Params
+/// ```ignore
+/// implement_error_display!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error, implement_error_display};
+/// use std::env::VarError;
+/// use std::fmt::{Display, Formatter};
+///
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// impl Display for ErrorKind {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             ErrorKind::Var => write!(f, "Var"),
+///         }
+///     }
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// implement_error!(StructError, VarError, ErrorKind::Var);
+/// // Implement Display for StructError.
+/// implement_error_display!(StructError);
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.to_string(), "kind: Var message: environment variable not found");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_display!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Display for StructError {
+///    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///        write!(f, "kind: {} message: {}", self.kind.to_string(), self.message)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_display {
+    ($struct_error:path) => {
+        impl $crate::__private::fmt::Display for $struct_error {
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter<'_>) -> $crate::__private::fmt::Result {
+                let kind = $crate::__private::ToString::to_string(&self.kind);
+                write!(f, "kind: {} message: {}", kind, self.message)
+            }
+        }
+    };
+}
+
+/// Implement the [`Error`](std::error::Error) trait for an struct with a source attribute.
+///
+/// The struct must have a `source: Option<Box<dyn std::error::Error>>` attribute, like the one
+/// filled by [`implement_in_error_in_struct!`], and implement [`Debug`] and
+/// [`Display`](std::fmt::Display).
+///
+/// # Params
+/// ```ignore
+/// implement_std_error!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_in_error_in_struct, implement_std_error};
+/// use std::error::Error;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// #[derive(Debug)]
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn Error>>
+/// }
+///
+/// impl std::fmt::Display for StructError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         std::fmt::Display::fmt(&self.message, f)
+///     }
+/// }
+///
+/// implement_in_error_in_struct!(StructError, io::Error, ErrorKind::IO);
+/// // Implement Error for StructError.
+/// implement_std_error!(StructError);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// let source = err.source().unwrap();
+/// assert_eq!(source.downcast_ref::<io::Error>().unwrap().kind(), io::ErrorKind::NotFound);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_std_error!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Error for StructError {
+///    fn source(&self) -> Option<&(dyn Error + 'static)> {
+///        self.source.as_deref()
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_std_error {
+    ($struct_error:path) => {
+        impl $crate::__private::Error for $struct_error {
+            fn source(&self) -> Option<&(dyn $crate::__private::Error + 'static)> {
+                self.source.as_deref()
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for a tuple struct with kind and message positions.
+///
+/// The first position of the tuple struct is the kind, and the second one is the message.
+///
+/// # Params
+/// ```ignore
+/// implement_tuple_error!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_tuple_error;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct TupleError(ErrorKind, String);
+///
+/// // Implement From<io::Error> for TupleError.
+/// implement_tuple_error!(TupleError, io::Error, ErrorKind::IO);
+///
+/// let err = TupleError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err, TupleError(ErrorKind::IO, "oh no!".to_string()));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_tuple_error!(TupleError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for TupleError {
+///    fn from(err: io::Error) -> Self {
+///        TupleError(ErrorKind::IO, err.to_string())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_tuple_error {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error($error_kind, $crate::__private::ToString::to_string(&error))
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, prefixing the message with a
+/// context.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_context!($struct_error, $error_type, $error_kind, $context);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_context;
+/// use std::env::VarError;
+///
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<VarError> for StructError.
+/// implement_error_with_context!(StructError, VarError, ErrorKind::Var, "while reading config");
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.message, "while reading config: environment variable not found");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_context!(StructError, VarError, ErrorKind::Var, "while reading config");
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<VarError> for StructError {
+///    fn from(err: VarError) -> Self {
+///        Self {
+///            kind: ErrorKind::Var,
+///            message: format!("{}: {}", "while reading config", err),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_context {
+    ($struct_error:path, $error_type: path, $error_kind: path, $context: literal) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::format!("{}: {}", $context, error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`TryFrom`] trait for an error, using a closure to convert the error.
+///
+/// The closure receives the error by value and must return a `Result<$struct_error, $error_type>`,
+/// giving back the original error when it can not be converted:
+/// ```ignore
+/// |error: $error_type| -> Result<$struct_error, $error_type>
+/// ```
+///
+/// The error type can not have a [`From`] implementation for the same error, because [`TryFrom`]
+/// is already implemented for every [`From`] implementation.
+///
+/// # Params
+/// ```ignore
+/// implement_try_from_error!($struct_error, $error_type, $convert);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_try_from_error;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     NotFound,
+/// }
+///
+/// #[derive(Debug)]
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement TryFrom<io::Error> for StructError, only for not found errors.
+/// implement_try_from_error!(StructError, io::Error, |error: io::Error| match error.kind() {
+///     io::ErrorKind::NotFound => Ok(StructError {
+///         kind: ErrorKind::NotFound,
+///         message: error.to_string(),
+///     }),
+///     _ => Err(error),
+/// });
+///
+/// let err = StructError::try_from(io::Error::from(io::ErrorKind::NotFound)).unwrap();
+/// assert_eq!(err.kind, ErrorKind::NotFound);
+///
+/// let err = StructError::try_from(io::Error::from(io::ErrorKind::PermissionDenied)).unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_try_from_error!(StructError, io::Error, convert);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl TryFrom<io::Error> for StructError {
+///    type Error = io::Error;
+///
+///    fn try_from(err: io::Error) -> Result<Self, Self::Error> {
+///        (convert)(err)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_try_from_error {
+    ($struct_error:path, $error_type: path, $convert: expr) => {
+        impl $crate::__private::TryFrom<$error_type> for $struct_error {
+            type Error = $error_type;
+
+            fn try_from(error: $error_type) -> $crate::__private::Result<$struct_error, $error_type> {
+                ($convert)(error)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, recording the location of the
+/// conversion in a `location: &'static Location<'static>` attribute.
+///
+/// The location is the one of the `?` operator, or the `.into()` / `from()` call, that converts
+/// the error, not the place where the original error was created.
+///
+/// Expands into [`implement_error_extra!`] with a `location` attribute, and `#[track_caller]` on
+/// `from`.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_location!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_location;
+/// use std::io;
+/// use std::panic::Location;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     location: &'static Location<'static>,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_location!(StructError, io::Error, ErrorKind::IO);
+///
+/// fn foo() -> Result<(), StructError> {
+///     Err(io::Error::from(io::ErrorKind::NotFound))?
+/// }
+///
+/// let err = foo().unwrap_err();
+/// assert_eq!(err.location.file(), file!());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_location!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    #[track_caller]
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            location: Location::caller(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_location {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        $crate::implement_error_extra!(#[track_caller] $struct_error, $error_type, $error_kind, {
+            location: $crate::__private::Location::caller()
+        });
+    };
+}
+
+/// Implement the [`From`] trait for an enum with a struct-style variant with kind and source
+/// attributes.
+///
+/// # Params
+/// ```ignore
+/// implement_error_in_enum_with_kind!($enum_error, $err_type, $enum_variant, $kind);
+/// ```
+///
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_in_enum_with_kind;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// #[derive(Debug)]
+/// pub enum EnumError {
+///     IO { kind: ErrorKind, source: io::Error },
+/// }
+///
+/// // Implement From<io::Error> for EnumError.
+/// implement_error_in_enum_with_kind!(EnumError, io::Error, EnumError::IO, ErrorKind::IO);
+///
+/// let EnumError::IO { kind, source } = EnumError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(kind, ErrorKind::IO);
+/// assert_eq!(source.kind(), io::ErrorKind::NotFound);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_in_enum_with_kind!(EnumError, io::Error, EnumError::IO, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for EnumError {
+///    fn from(err: io::Error) -> Self {
+///        EnumError::IO {
+///            kind: ErrorKind::IO,
+///            source: err,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_in_enum_with_kind {
+    ($enum_error:ident, $err_type: path, $enum_variant: path, $kind: path) => {
+        impl From<$err_type> for $enum_error {
+            fn from(error: $err_type) -> $enum_error {
+                $enum_variant {
+                    kind: $kind,
+                    source: error,
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an enum with a struct-style variant.
+///
+/// Only variants with a single field are supported; the field is filled with the error.
+///
+/// # Params
+/// ```ignore
+/// implement_error_in_struct_enum!($enum_error, $err_type, $enum_variant, $field);
+/// ```
+///
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_in_struct_enum;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum EnumError {
+///     IO { source: io::Error },
+/// }
+///
+/// // Implement From<io::Error> for EnumError.
+/// implement_error_in_struct_enum!(EnumError, io::Error, EnumError::IO, source);
+///
+/// let EnumError::IO { source } = EnumError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(source.kind(), io::ErrorKind::NotFound);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_in_struct_enum!(EnumError, io::Error, EnumError::IO, source);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for EnumError {
+///    fn from(err: io::Error) -> Self {
+///        EnumError::IO { source: err }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_in_struct_enum {
+    ($enum_error:ident, $err_type: path, $enum_variant: path, $field: ident) => {
+        impl From<$err_type> for $enum_error {
+            fn from(error: $err_type) -> $enum_error {
+                $enum_variant { $field: error }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, building the message with a
+/// closure.
+///
+/// The closure receives a reference to the error and returns the message:
+/// ```ignore
+/// |error: &$error_type| -> String
+/// ```
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_msg_fn!($struct_error, $error_type, $error_kind, $message);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_msg_fn;
+/// use std::env::VarError;
+///
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<VarError> for StructError, using the Debug format as message.
+/// implement_error_with_msg_fn!(StructError, VarError, ErrorKind::Var, |e| format!("{e:?}"));
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.message, format!("{:?}", VarError::NotPresent));
+///
+/// pub struct ConfigError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // The closure can capture values too.
+/// implement_error_with_msg_fn!(ConfigError, VarError, ErrorKind::Var, {
+///     let prefix = String::from("config");
+///     move |e| format!("{prefix}: {e}")
+/// });
+///
+/// let err = ConfigError::from(VarError::NotPresent);
+/// assert_eq!(err.message, "config: environment variable not found");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_msg_fn!(StructError, VarError, ErrorKind::Var, message);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<VarError> for StructError {
+///    fn from(err: VarError) -> Self {
+///        Self {
+///            kind: ErrorKind::Var,
+///            message: message(&err),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_msg_fn {
+    ($struct_error:path, $error_type: path, $error_kind: path, $message: expr) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let message = $crate::__private::by_ref::<$error_type, $crate::__private::String, _>($message);
+                $struct_error {
+                    kind: $error_kind,
+                    message: message(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Define an error enum, with a variant per error type, and implement the [`From`],
+/// [`Display`](std::fmt::Display) and [`Error`](std::error::Error) traits for it.
+///
+/// The enum derives [`Debug`]. Each variant displays the message of its error, or the name of the
+/// variant if the message is empty, and returns its error as source.
+///
+/// **WARNING**: you might prefer to use [thiserror] instead of this macro.
+///
+/// # Params
+/// ```ignore
+/// define_error_enum!($vis $enum_error { $variant($err_type), ... });
+/// ```
+///
+/// # Example
+/// ```
+/// use heimdall_errors::define_error_enum;
+/// use std::env::VarError;
+/// use std::error::Error;
+/// use std::fmt;
+/// use std::io;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// pub struct SilentError;
+///
+/// impl fmt::Display for SilentError {
+///     fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         Ok(())
+///     }
+/// }
+///
+/// impl Error for SilentError {}
+///
+/// define_error_enum!(pub EnumError {
+///     IO(io::Error),
+///     Var(VarError),
+///     Parse(ParseIntError),
+///     Silent(SilentError),
+/// });
+///
+/// let err = EnumError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err.to_string(), "oh no!");
+///
+/// let err = EnumError::from(VarError::NotPresent);
+/// assert_eq!(err.to_string(), "environment variable not found");
+/// assert!(err.source().unwrap().is::<VarError>());
+///
+/// let err = EnumError::from("heimdall".parse::<u8>().unwrap_err());
+/// assert_eq!(err.to_string(), "invalid digit found in string");
+///
+/// let err = EnumError::from(SilentError);
+/// assert_eq!(err.to_string(), "Silent");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// define_error_enum!(pub EnumError { IO(io::Error) });
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// #[derive(Debug)]
+/// pub enum EnumError {
+///     IO(io::Error),
+/// }
+///
+/// impl Display for EnumError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             EnumError::IO(err) => {
+///                 let message = err.to_string();
+///                 if message.is_empty() {
+///                     f.write_str("IO")
+///                 } else {
+///                     f.write_str(&message)
+///                 }
+///             }
+///         }
+///     }
+/// }
+///
+/// impl Error for EnumError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         match self {
+///             EnumError::IO(err) => Some(err),
+///         }
+///     }
+/// }
+///
+/// impl From<io::Error> for EnumError {
+///    fn from(err: io::Error) -> Self {
+///        EnumError::IO(err)
+///     }
+/// }
+/// ```
+///
+/// [thiserror]:https://crates.io/crates/thiserror
+#[macro_export]
+macro_rules! define_error_enum {
+    ($(#[$attr:meta])* $vis:vis $enum_error:ident { $($variant:ident($err_type: path)),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        $vis enum $enum_error {
+            $($variant($err_type),)*
+        }
+
+        impl $crate::__private::fmt::Display for $enum_error {
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter<'_>) -> $crate::__private::fmt::Result {
+                match self {
+                    $(
+                        $enum_error::$variant(error) => {
+                            let message = $crate::__private::ToString::to_string(error);
+                            if message.is_empty() {
+                                f.write_str(stringify!($variant))
+                            } else {
+                                f.write_str(&message)
+                            }
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl $crate::__private::Error for $enum_error {
+            fn source(&self) -> Option<&(dyn $crate::__private::Error + 'static)> {
+                match self {
+                    $($enum_error::$variant(error) => Some(error),)*
+                }
+            }
+        }
+
+        $(
+            $crate::implement_error_in_enum!($enum_error, $err_type, $enum_error::$variant);
+        )*
+    };
+}
+
+/// Implement a named constructor for an struct with kind structure.
+///
+/// The constructor builds the same value as the [`From`] implementation of [`implement_error!`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_named_ctor!($struct_error, $error_type, $error_kind, $ctor);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_named_ctor;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement StructError::from_io(io::Error).
+/// implement_error_named_ctor!(StructError, io::Error, ErrorKind::IO, from_io);
+///
+/// let err = StructError::from_io(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert_eq!(err.message, "oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_named_ctor!(StructError, io::Error, ErrorKind::IO, from_io);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///    pub fn from_io(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_named_ctor {
+    ($struct_error:path, $error_type: path, $error_kind: path, $ctor: ident) => {
+        impl $struct_error {
+            pub fn $ctor(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind, message and a thread safe source
+/// attributes.
+///
+/// The source attribute must be an `Option<Box<dyn Error + Send + Sync>>`, so the error type must
+/// be [`Send`] and [`Sync`]. Unlike [`implement_in_error_in_struct!`], the resulting struct can be
+/// sent to other threads, which is required to return it from spawned threads or from futures
+/// run by multi-threaded async runtimes.
+///
+/// # Params
+/// ```ignore
+/// implement_error_keep_source!($struct_error, $err_type, $kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_keep_source;
+/// use std::error::Error;
+/// use std::{io, thread};
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind{
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn Error + Send + Sync>>
+/// }
+///
+/// // Implement From<std::io::Error> for StructError.
+/// implement_error_keep_source!(StructError, io::Error, ErrorKind::IO);
+///
+/// let handle = thread::spawn(|| -> Result<(), StructError> {
+///     Err(io::Error::from(io::ErrorKind::NotFound))?
+/// });
+///
+/// let err = handle.join().unwrap().unwrap_err();
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert!(err.source.unwrap().is::<io::Error>());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_keep_source!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        let message = err.to_string();
+///        let source: Box<dyn Error + Send + Sync + 'static> = Box::new(err);
+///        Self {
+///            kind: ErrorKind::IO,
+///            message,
+///            source: Some(source),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_keep_source {
+    ($struct_error:path, $err_type: path, $kind: path) => {
+        impl From<$err_type> for $struct_error {
+            fn from(err: $err_type) -> Self {
+                let message = $crate::__private::ToString::to_string(&err);
+                let source: $crate::__private::Box<dyn $crate::__private::Error + Send + Sync + 'static> =
+                    $crate::__private::Box::new(err);
+                Self {
+                    kind: $kind,
+                    message,
+                    source: Some(source),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with an specific structure, with a [`Send`] and
+/// [`Sync`] source.
+///
+/// Thread safe sibling of [`implement_in_error_in_struct!`]: the source attribute must be an
+/// `Option<Box<dyn Error + Send + Sync + 'static>>`, so the error type must be [`Send`],
+/// [`Sync`] and `'static`. It forwards its arguments to [`implement_error_keep_source!`] instead of
+/// generating its own implementation.
+///
+/// The resulting struct is [`Send`] and [`Sync`], so it can be returned by futures spawned in
+/// multi-threaded async runtimes, like `tokio::spawn`.
+///
+/// # Params
+/// ```ignore
+/// implement_in_error_in_struct_sync!($struct_error, $err_type, $kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_in_error_in_struct_sync;
+///
+/// pub enum ErrorKind{
+///     IO,
+/// }
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>
+/// }
+///
+/// // Implement From<std::io::Error> for StructError.
+/// implement_in_error_in_struct_sync!(StructError, std::io::Error, ErrorKind::IO);
+///
+/// // Fails to compile if StructError stops being Send + Sync + 'static.
+/// fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+/// assert_send_sync_static::<StructError>();
+///
+/// async fn read() -> Result<(), StructError> {
+///     Err(std::io::Error::from(std::io::ErrorKind::NotFound))?
+/// }
+///
+/// fn assert_send<T: Send>(_: T) {}
+/// assert_send(read());
+/// ```
+#[macro_export]
+macro_rules! implement_in_error_in_struct_sync {
+    ($struct_error:path, $err_type: path, $kind: path) => {
+        $crate::implement_error_keep_source!($struct_error, $err_type, $kind);
+    };
+}
+
+/// Implement the [`From`] trait for a generic struct with kind, message and payload attributes.
+///
+/// The payload is filled with its [`Default`] value, so the generic parameters used by the payload
+/// must be bounded by [`Default`] in the where clause.
+///
+/// # Params
+/// ```ignore
+/// implement_error_generic!($struct_error<$param, ...>, $error_type, $error_kind, where $bounds);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_generic;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError<T> {
+///     kind: ErrorKind,
+///     message: String,
+///     payload: T,
+/// }
+///
+/// // Implement From<io::Error> for StructError<T>.
+/// implement_error_generic!(StructError<T>, io::Error, ErrorKind::IO, where T: Default);
+///
+/// let err: StructError<Vec<u8>> = io::Error::new(io::ErrorKind::Other, "oh no!").into();
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert_eq!(err.message, "oh no!");
+/// assert!(err.payload.is_empty());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_generic!(StructError<T>, io::Error, ErrorKind::IO, where T: Default);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl<T> From<io::Error> for StructError<T> where T: Default {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            payload: Default::default(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_generic {
+    ($struct_error:ident<$($param:ident),+ $(,)?>, $error_type: path, $error_kind: path $(, where $($bounds:tt)+)?) => {
+        impl<$($param),+> From<$error_type> for $struct_error<$($param),+> $(where $($bounds)+)? {
+            fn from(error: $error_type) -> Self {
+                Self {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    payload: Default::default(),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for `&str` and [String] in an enum with a string variant.
+///
+/// # Params
+/// ```ignore
+/// implement_from_string_variants!($enum_error, $enum_variant);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_from_string_variants;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum EnumError {
+///     Message(String)
+/// }
+///
+/// // Implement From<&str> and From<String> for EnumError.
+/// implement_from_string_variants!(EnumError, EnumError::Message);
+///
+/// assert_eq!(EnumError::from("x"), EnumError::Message("x".to_string()));
+/// assert_eq!(EnumError::from(String::from("y")), EnumError::Message("y".to_string()));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_from_string_variants!(EnumError, EnumError::Message);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<&str> for EnumError {
+///    fn from(message: &str) -> Self {
+///        EnumError::Message(message.to_string())
+///     }
+/// }
+///
+/// impl From<String> for EnumError {
+///    fn from(message: String) -> Self {
+///        EnumError::Message(message)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_from_string_variants {
+    ($enum_error:ident, $enum_variant: path) => {
+        impl From<&str> for $enum_error {
+            fn from(message: &str) -> $enum_error {
+                $enum_variant($crate::__private::ToString::to_string(message))
+            }
+        }
+
+        impl From<$crate::__private::String> for $enum_error {
+            fn from(message: $crate::__private::String) -> $enum_error {
+                $enum_variant(message)
+            }
+        }
+    };
+}
+
+/// Define a [`Result`] type alias for an error.
+///
+/// # Params
+/// ```ignore
+/// define_error_result_alias!($error, $alias);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{define_error_result_alias, implement_error};
+/// use std::env::{var, VarError};
+///
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// implement_error!(StructError, VarError, ErrorKind::Var);
+/// // Define StructResult<T> as Result<T, StructError>.
+/// define_error_result_alias!(StructError, StructResult);
+///
+/// fn foo() -> StructResult<()> {
+///     var("INEXIST_ENV_VAR")?;
+///     Ok(())
+/// }
+///
+/// assert!(foo().is_err());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// define_error_result_alias!(StructError, StructResult);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// pub type StructResult<T> = std::result::Result<T, StructError>;
+/// ```
+#[macro_export]
+macro_rules! define_error_result_alias {
+    ($error:path, $alias:ident) => {
+        pub type $alias<T> = $crate::__private::Result<T, $error>;
+    };
+}
+
+/// Implement a `status_code` method for an struct with kind structure, mapping each kind to an
+/// HTTP status code.
+///
+/// The mapping is a match over the kind, so it must be exhaustive: either list every kind or
+/// add a fallback `_ => $status` entry at the end.
+///
+/// # Params
+/// ```ignore
+/// implement_http_status!($struct_error, [$kind => $status, ...]);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_http_status;
+///
+/// pub enum ErrorKind {
+///     NotFound,
+///     IO,
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement StructError::status_code().
+/// implement_http_status!(StructError, [
+///     ErrorKind::NotFound => 404,
+///     ErrorKind::IO => 500,
+///     ErrorKind::Var => 400,
+/// ]);
+///
+/// let err = StructError { kind: ErrorKind::NotFound, message: String::new() };
+/// assert_eq!(err.status_code(), 404);
+///
+/// let err = StructError { kind: ErrorKind::IO, message: String::new() };
+/// assert_eq!(err.status_code(), 500);
+///
+/// let err = StructError { kind: ErrorKind::Var, message: String::new() };
+/// assert_eq!(err.status_code(), 400);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_http_status!(StructError, [ErrorKind::NotFound => 404, _ => 500]);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///     pub fn status_code(&self) -> u16 {
+///         match &self.kind {
+///             ErrorKind::NotFound => 404,
+///             _ => 500,
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_http_status {
+    ($struct_error:path, [$($kind: pat => $status: expr),* $(,)?]) => {
+        impl $struct_error {
+            pub fn status_code(&self) -> u16 {
+                match &self.kind {
+                    $($kind => $status,)*
+                }
+            }
+        }
+    };
+}
+
+/// Implement the `serde::Serialize` trait for an struct with kind structure.
+///
+/// Available with the `serde` feature. The struct is serialized with `kind` and `message`
+/// attributes, where the kind is serialized using its [ToString] implementation. Other
+/// attributes, like the source, are not serialized.
+///
+/// # Params
+/// ```ignore
+/// implement_error_serialize!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error_serialize, implement_in_error_in_struct};
+/// use std::fmt::{Display, Formatter};
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// impl Display for ErrorKind {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             ErrorKind::IO => write!(f, "IO"),
+///         }
+///     }
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error>>
+/// }
+///
+/// implement_in_error_in_struct!(StructError, io::Error, ErrorKind::IO);
+/// // Implement Serialize for StructError.
+/// implement_error_serialize!(StructError);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// let json = serde_json::to_value(&err).unwrap();
+/// assert_eq!(json["kind"], "IO");
+/// assert_eq!(json["message"], "oh no!");
+///
+/// mod errors {
+///     pub struct PathError {
+///         pub kind: String,
+///         pub message: String,
+///     }
+/// }
+///
+/// // Paths are accepted too; the struct is named after the last segment, `PathError`.
+/// implement_error_serialize!(errors::PathError);
+///
+/// let err = errors::PathError { kind: "IO".to_string(), message: "oh no!".to_string() };
+/// let json = serde_json::to_value(&err).unwrap();
+/// assert_eq!(json["kind"], "IO");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_serialize!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Serialize for StructError {
+///     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+///     where
+///         S: Serializer,
+///     {
+///         let mut state = serializer.serialize_struct("StructError", 2)?;
+///         state.serialize_field("kind", &self.kind.to_string())?;
+///         state.serialize_field("message", &self.message)?;
+///         state.end()
+///     }
+/// }
+/// ```
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! implement_error_serialize {
+    ($struct_error:path) => {
+        impl $crate::__private::serde::Serialize for $struct_error {
+            fn serialize<S>(&self, serializer: S) -> $crate::__private::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                use $crate::__private::serde::ser::SerializeStruct;
+
+                let name = $crate::__private::type_name(stringify!($struct_error));
+                let mut state = serializer.serialize_struct(name, 2)?;
+                state.serialize_field("kind", &$crate::__private::ToString::to_string(&self.kind))?;
+                state.serialize_field("message", &self.message)?;
+                state.end()
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for boxed errors in an enum with a catch-all variant.
+///
+/// The variant must hold a `Box<dyn Error + Send + Sync>`. This implementation conflicts with
+/// any other [`From`] implementation for the same boxed type, like the one of
+/// [`implement_error_in_enum!`] for `Box<dyn Error + Send + Sync>`.
+///
+/// # Params
+/// ```ignore
+/// implement_boxed_error_in_enum!($enum_error, $enum_variant);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_boxed_error_in_enum;
+/// use std::error::Error;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum EnumError {
+///     Other(Box<dyn Error + Send + Sync>)
+/// }
+///
+/// // Implement From<Box<dyn Error + Send + Sync>> for EnumError.
+/// implement_boxed_error_in_enum!(EnumError, EnumError::Other);
+///
+/// let boxed: Box<dyn Error + Send + Sync> = Box::new(io::Error::from(io::ErrorKind::NotFound));
+/// let EnumError::Other(err) = EnumError::from(boxed);
+/// assert!(err.is::<io::Error>());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_boxed_error_in_enum!(EnumError, EnumError::Other);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<Box<dyn Error + Send + Sync>> for EnumError {
+///    fn from(err: Box<dyn Error + Send + Sync>) -> Self {
+///        EnumError::Other(err)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_boxed_error_in_enum {
+    ($enum_error:ident, $enum_variant: path) => {
+        impl From<$crate::__private::Box<dyn $crate::__private::Error + Send + Sync>> for $enum_error {
+            fn from(error: $crate::__private::Box<dyn $crate::__private::Error + Send + Sync>) -> $enum_error {
+                $enum_variant(error)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure and a numeric code attribute.
+///
+/// Expands into [`implement_error_extra!`] with a `code` attribute.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_code!($struct_error, $error_type, $error_kind, $code);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_code;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     code: u32,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_code!(StructError, io::Error, ErrorKind::IO, 1001);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.code, 1001);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_code!(StructError, io::Error, ErrorKind::IO, 1001);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            code: 1001,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_code {
+    ($struct_error:path, $error_type: path, $error_kind: path, $code: expr) => {
+        $crate::implement_error_extra!($struct_error, $error_type, $error_kind, { code: $code });
+    };
+}
+
+/// Implement the [`From`] trait between two structs with kind structure, translating the kind of
+/// the inner error with a closure.
+///
+/// The closure receives a reference to the inner error and returns the kind of the outer error:
+/// ```ignore
+/// |inner: &$inner_error| -> OuterKind
+/// ```
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_error!($struct_error, $inner_error, $kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error, implement_error_display, implement_error_from_error};
+/// use std::env::VarError;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum InnerKind {
+///     Var,
+/// }
+///
+/// impl std::fmt::Display for InnerKind {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{self:?}")
+///     }
+/// }
+///
+/// pub struct InnerError {
+///     kind: InnerKind,
+///     message: String,
+/// }
+///
+/// impl InnerError {
+///     pub fn kind(&self) -> InnerKind {
+///         self.kind
+///     }
+/// }
+///
+/// implement_error!(InnerError, VarError, InnerKind::Var);
+/// implement_error_display!(InnerError);
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum OuterKind {
+///     Config,
+/// }
+///
+/// impl From<InnerKind> for OuterKind {
+///     fn from(kind: InnerKind) -> Self {
+///         match kind {
+///             InnerKind::Var => OuterKind::Config,
+///         }
+///     }
+/// }
+///
+/// pub struct OuterError {
+///     kind: OuterKind,
+///     message: String,
+/// }
+///
+/// // Implement From<InnerError> for OuterError.
+/// implement_error_from_error!(OuterError, InnerError, |inner| OuterKind::from(inner.kind()));
+///
+/// let err = OuterError::from(InnerError::from(VarError::NotPresent));
+/// assert_eq!(err.kind, OuterKind::Config);
+/// assert_eq!(err.message, "kind: Var message: environment variable not found");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_error!(OuterError, InnerError, kind);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<InnerError> for OuterError {
+///    fn from(inner: InnerError) -> Self {
+///        Self {
+///            kind: kind(&inner),
+///            message: inner.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_from_error {
+    ($struct_error:path, $inner_error: path, $kind: expr) => {
+        impl From<$inner_error> for $struct_error {
+            fn from(inner: $inner_error) -> $struct_error {
+                let kind = $crate::__private::by_ref::<$inner_error, _, _>($kind);
+                $struct_error {
+                    kind: kind(&inner),
+                    message: $crate::__private::ToString::to_string(&inner),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for a newtype wrapping an error, through the [`From`]
+/// implementation of the wrapped error.
+///
+/// # Params
+/// ```ignore
+/// implement_newtype_from!($newtype, $error_type);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error, implement_newtype_from};
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// implement_error!(StructError, io::Error, ErrorKind::IO);
+///
+/// pub struct ServiceError(StructError);
+/// pub struct ApiError(ServiceError);
+///
+/// // Implement From<io::Error> for ServiceError and ApiError.
+/// implement_newtype_from!(ServiceError, io::Error);
+/// implement_newtype_from!(ApiError, io::Error);
+///
+/// fn foo() -> Result<(), ApiError> {
+///     Err(io::Error::from(io::ErrorKind::NotFound))?
+/// }
+///
+/// let ApiError(ServiceError(err)) = foo().unwrap_err();
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_newtype_from!(ApiError, io::Error);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for ApiError {
+///    fn from(err: io::Error) -> Self {
+///        ApiError(From::from(err))
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_newtype_from {
+    ($newtype:path, $error_type: path) => {
+        impl From<$error_type> for $newtype {
+            fn from(error: $error_type) -> $newtype {
+                $newtype(From::from(error))
+            }
+        }
+    };
+}
+
+/// Implement the [`Debug`] trait for an struct with kind, message and source attributes, printing
+/// the whole chain of sources.
+///
+/// The kind must implement [`Debug`], and the source attribute must be an
+/// `Option<Box<dyn Error>>`, with or without [`Send`] and [`Sync`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_debug!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error_debug, implement_in_error_in_struct, implement_std_error};
+/// use std::error::Error;
+/// use std::fmt::{Display, Formatter};
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum ErrorKind {
+///     IO,
+///     Service,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn Error>>
+/// }
+///
+/// impl Display for StructError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         Display::fmt(&self.message, f)
+///     }
+/// }
+///
+/// implement_in_error_in_struct!(StructError, io::Error, ErrorKind::IO);
+/// implement_std_error!(StructError);
+/// // Implement Debug for StructError.
+/// implement_error_debug!(StructError);
+///
+/// let inner = StructError::from(io::Error::new(io::ErrorKind::Other, "disk failure"));
+/// let err = StructError {
+///     kind: ErrorKind::Service,
+///     message: "service failure".to_string(),
+///     source: Some(Box::new(inner)),
+/// };
+///
+/// assert_eq!(format!("{err:?}"), "\
+/// StructError { kind: Service, message: \"service failure\" }
+///
+/// Caused by:
+///     0: disk failure
+///     1: disk failure");
+///
+/// mod errors {
+///     pub struct PathError {
+///         pub kind: String,
+///         pub message: String,
+///         pub source: Option<Box<dyn std::error::Error>>,
+///     }
+/// }
+///
+/// // Paths are accepted too; the struct is named after the last segment.
+/// implement_error_debug!(errors::PathError);
+///
+/// let err = errors::PathError { kind: "IO".to_string(), message: "oh no!".to_string(), source: None };
+/// assert_eq!(format!("{err:?}"), "PathError { kind: \"IO\", message: \"oh no!\" }");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_debug!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Debug for StructError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "StructError {{ kind: {:?}, message: {:?} }}", self.kind, self.message)?;
+///
+///         let mut source: Option<&dyn Error> = match &self.source {
+///             Some(source) => Some(&**source),
+///             None => None,
+///         };
+///         if source.is_some() {
+///             write!(f, "\n\nCaused by:")?;
+///         }
+///
+///         let mut index = 0;
+///         while let Some(error) = source {
+///             write!(f, "\n    {index}: {error}")?;
+///             source = error.source();
+///             index += 1;
+///         }
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_debug {
+    ($struct_error:path) => {
+        impl $crate::__private::fmt::Debug for $struct_error {
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter<'_>) -> $crate::__private::fmt::Result {
+                write!(
+                    f,
+                    "{} {{ kind: {:?}, message: {:?} }}",
+                    $crate::__private::type_name(stringify!($struct_error)),
+                    self.kind,
+                    self.message,
+                )?;
+
+                let mut source: Option<&dyn $crate::__private::Error> = match &self.source {
+                    Some(source) => Some(&**source),
+                    None => None,
+                };
+                if source.is_some() {
+                    write!(f, "\n\nCaused by:")?;
+                }
+
+                let mut index = 0;
+                while let Some(error) = source {
+                    write!(f, "\n    {}: {}", index, error)?;
+                    source = $crate::__private::Error::source(error);
+                    index += 1;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, prefixing the message with the
+/// kind.
+///
+/// The kind is printed using its [ToString] implementation.
+///
+/// # Params
+/// ```ignore
+/// implement_error_prefixed_kind!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_prefixed_kind;
+/// use std::fmt::{Display, Formatter};
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum ErrorKind {
+///     Io,
+/// }
+///
+/// impl Display for ErrorKind {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{self:?}")
+///     }
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_prefixed_kind!(StructError, io::Error, ErrorKind::Io);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert!(err.message.starts_with("[Io]"));
+/// assert_eq!(err.message, "[Io] oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_prefixed_kind!(StructError, io::Error, ErrorKind::Io);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::Io,
+///            message: format!("[{}] {}", ErrorKind::Io.to_string(), err),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_prefixed_kind {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let kind = $error_kind;
+                $struct_error {
+                    message: $crate::__private::format!(
+                        "[{}] {}",
+                        $crate::__private::ToString::to_string(&kind),
+                        error,
+                    ),
+                    kind,
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, where the kind is an
+/// expression.
+///
+/// Unlike [`implement_error!`], the kind can be any expression, like a variant with parameters.
+///
+/// # Params
+/// ```ignore
+/// implement_error_expr!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_expr;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Http(u16),
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_expr!(StructError, io::Error, ErrorKind::Http(500));
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.kind, ErrorKind::Http(500));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_expr!(StructError, io::Error, ErrorKind::Http(500));
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::Http(500),
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_expr {
+    ($struct_error:path, $error_type: path, $error_kind: expr) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for a reference to an error in an struct with kind structure.
+///
+/// The error is only borrowed to build the message, so it is not consumed.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_ref!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_ref;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<&io::Error> for StructError.
+/// implement_error_from_ref!(StructError, io::Error, ErrorKind::IO);
+///
+/// let io_err = io::Error::new(io::ErrorKind::Other, "oh no!");
+/// let err = StructError::from(&io_err);
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert_eq!(err.message, io_err.to_string());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_ref!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<&io::Error> for StructError {
+///    fn from(err: &io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_from_ref {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<&$error_type> for $struct_error {
+            fn from(error: &$error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind, message and source attributes, where
+/// the kind is the owned value returned by the `kind()` method of the error.
+///
+/// It is the [`implement_error_with_source!`] counterpart of
+/// [`implement_error_with_owned_kind!`]: the kind is not cloned, so the error must have a
+/// `kind(&self)` method returning an owned value, like [`std::io::Error`].
+///
+/// # Params
+/// ```ignore
+/// implement_full_error!($err, $t, $kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_full_error;
+/// use std::error::Error;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO(io::ErrorKind),
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn Error>>
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_full_error!(StructError, io::Error, ErrorKind::IO);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.kind, ErrorKind::IO(io::ErrorKind::NotFound));
+///
+/// let source = err.source.unwrap();
+/// assert_eq!(source.downcast_ref::<io::Error>().unwrap().kind(), io::ErrorKind::NotFound);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_full_error!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        let kind = ErrorKind::IO(err.kind());
+///        Self {
+///            kind,
+///            message: err.to_string(),
+///            source: Some(Box::new(err)),
+///        }
+///     }
+/// }
+/// ```
+///
+/// The kind is read before the error is moved into the source.
+#[macro_export]
+macro_rules! implement_full_error {
+    ($err:path, $t: path, $kind: path) => {
+        impl From<$t> for $err {
+            fn from(error: $t) -> $err {
+                let kind = $kind(error.kind());
+                $err {
+                    kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    source: Some($crate::__private::Box::new(error)),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, where the message is any type
+/// implementing `From<String>`, like `Box<str>` or `Cow<'static, str>`.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_into_message!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_into_message;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: Box<str>,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_into_message!(StructError, io::Error, ErrorKind::IO);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(&*err.message, "oh no!");
+/// ```
+///
+/// With a `Cow<'static, str>` message:
+/// ```
+/// use heimdall_errors::implement_error_with_into_message;
+/// use std::borrow::Cow;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: Cow<'static, str>,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_into_message!(StructError, io::Error, ErrorKind::IO);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err.message, "oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_into_message!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string().into(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_into_message {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: From::from($crate::__private::ToString::to_string(&error)),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an enum with a struct-style variant with kind and source
+/// attributes.
+///
+/// Alias of [`implement_error_in_enum_with_kind!`]: it forwards its arguments to that macro
+/// unchanged, so both names always generate the same code.
+///
+/// # Params
+/// ```ignore
+/// implement_kinded_enum_error!($enum_error, $err_type, $enum_variant, $kind);
+/// ```
+///
+/// # Example
+/// ```
+/// use heimdall_errors::implement_kinded_enum_error;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub enum EnumError {
+///     IO { kind: ErrorKind, source: io::Error },
+/// }
+///
+/// // Implement From<io::Error> for EnumError.
+/// implement_kinded_enum_error!(EnumError, io::Error, EnumError::IO, ErrorKind::IO);
+///
+/// match EnumError::from(io::Error::from(io::ErrorKind::NotFound)) {
+///     EnumError::IO { kind: ErrorKind::IO, source } => {
+///         assert_eq!(source.kind(), io::ErrorKind::NotFound);
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_kinded_enum_error {
+    ($enum_error:ident, $err_type: path, $enum_variant: path, $kind: path) => {
+        $crate::implement_error_in_enum_with_kind!($enum_error, $err_type, $enum_variant, $kind);
+    };
+}
+
+/// Implement the [`From`] trait for [`std::io::Error`] from an error.
+///
+/// Available with the `std` feature. The error must implement
+/// [`Error`](std::error::Error), [`Send`] and [`Sync`], and is wrapped in an
+/// [`std::io::Error`] of kind [`Other`](std::io::ErrorKind::Other).
+///
+/// # Params
+/// ```ignore
+/// implement_into_io_error!($error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error, implement_into_io_error};
+/// use std::env::VarError;
+/// use std::fmt::{Display, Formatter};
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// #[derive(Debug)]
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// impl Display for StructError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         Display::fmt(&self.message, f)
+///     }
+/// }
+///
+/// impl std::error::Error for StructError {}
+///
+/// implement_error!(StructError, VarError, ErrorKind::Var);
+/// // Implement From<StructError> for io::Error.
+/// implement_into_io_error!(StructError);
+///
+/// fn foo() -> io::Result<()> {
+///     Err(StructError::from(VarError::NotPresent))?
+/// }
+///
+/// let err = foo().unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::Other);
+///
+/// let err = err.into_inner().unwrap().downcast::<StructError>().unwrap();
+/// assert_eq!(err.kind, ErrorKind::Var);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_into_io_error!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<StructError> for io::Error {
+///    fn from(err: StructError) -> Self {
+///        io::Error::new(io::ErrorKind::Other, err)
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! implement_into_io_error {
+    ($error:path) => {
+        impl From<$error> for $crate::__private::io::Error {
+            fn from(error: $error) -> $crate::__private::io::Error {
+                $crate::__private::io::Error::new($crate::__private::io::ErrorKind::Other, error)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure and extra attributes, filled with
+/// the given values.
+///
+/// The values are evaluated inside `from`, on every conversion. Outer attributes written before
+/// the struct, like `#[track_caller]`, are applied to the `from` function.
+///
+/// # Params
+/// ```ignore
+/// implement_error_extra!($(#[$attr])* $struct_error, $error_type, $error_kind, { $field: $value, ... });
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_extra;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     trace_id: Option<String>,
+///     retryable: bool,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_extra!(StructError, io::Error, ErrorKind::IO, { trace_id: None, retryable: false });
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.trace_id, None);
+/// assert!(!err.retryable);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_extra!(StructError, io::Error, ErrorKind::IO, { trace_id: None, retryable: false });
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            trace_id: None,
+///            retryable: false,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_extra {
+    ($(#[$attr: meta])* $struct_error:path, $error_type: path, $error_kind: path, { $($field: ident: $value: expr),* $(,)? }) => {
+        impl From<$error_type> for $struct_error {
+            $(#[$attr])*
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    $($field: $value,)*
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`PartialEq`] trait for an struct with kind, message and source attributes,
+/// ignoring the source.
+///
+/// The kind must implement [`PartialEq`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_partial_eq!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_partial_eq;
+/// use std::env::VarError;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Other,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error>>
+/// }
+///
+/// // Implement PartialEq for StructError.
+/// implement_error_partial_eq!(StructError);
+///
+/// let io_err = StructError {
+///     kind: ErrorKind::Other,
+///     message: "oh no!".to_string(),
+///     source: Some(Box::new(io::Error::from(io::ErrorKind::NotFound))),
+/// };
+/// let var_err = StructError {
+///     kind: ErrorKind::Other,
+///     message: "oh no!".to_string(),
+///     source: Some(Box::new(VarError::NotPresent)),
+/// };
+/// assert!(io_err == var_err);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_partial_eq!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl PartialEq for StructError {
+///    fn eq(&self, other: &Self) -> bool {
+///        self.kind == other.kind && self.message == other.message
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_partial_eq {
+    ($struct_error:path) => {
+        impl PartialEq for $struct_error {
+            fn eq(&self, other: &$struct_error) -> bool {
+                self.kind == other.kind && self.message == other.message
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with an specific structure, with ErrorKind,
+/// and message attributes, where the kind is built from the value returned by a method of the
+/// error.
+///
+/// The method is called without arguments, and must return a value accepted by the kind variant.
+/// With the `kind` method, it generates the same code as [`implement_error_with_owned_kind!`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_method!($err, $t, $kind, $method);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_method;
+/// use std::fmt::{Display, Formatter};
+///
+/// #[derive(Debug)]
+/// pub struct HttpError {
+///     code: u16,
+/// }
+///
+/// impl HttpError {
+///     pub fn code(&self) -> u16 {
+///         self.code
+///     }
+/// }
+///
+/// impl Display for HttpError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "HTTP error {}", self.code)
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Http(u16),
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+/// // Implement From<HttpError> for StructError.
+/// implement_error_with_method!(StructError, HttpError, ErrorKind::Http, code);
+///
+/// let err = StructError::from(HttpError { code: 404 });
+/// assert_eq!(err.kind, ErrorKind::Http(404));
+/// assert_eq!(err.message, "HTTP error 404");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_method!(StructError, HttpError, ErrorKind::Http, code);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<HttpError> for StructError {
+///    fn from(err: HttpError) -> Self {
+///        Self {
+///            kind: ErrorKind::Http(err.code()),
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_method {
+    ($err:path, $t: path, $kind: path, $method: ident) => {
+        impl From<$t> for $err {
+            fn from(error: $t) -> $err {
+                $err {
+                    kind: $kind(error.$method()),
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for a [Vec] of errors in an enum with a variant aggregating their
+/// messages.
+///
+/// # Params
+/// ```ignore
+/// implement_error_aggregate!($enum_error, $err_type, $enum_variant);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_aggregate;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum EnumError {
+///     Multiple(Vec<String>)
+/// }
+///
+/// // Implement From<Vec<io::Error>> for EnumError.
+/// implement_error_aggregate!(EnumError, io::Error, EnumError::Multiple);
+///
+/// let errors = vec![
+///     io::Error::new(io::ErrorKind::Other, "first"),
+///     io::Error::new(io::ErrorKind::Other, "second"),
+/// ];
+/// let err = EnumError::from(errors);
+/// assert_eq!(err, EnumError::Multiple(vec!["first".to_string(), "second".to_string()]));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_aggregate!(EnumError, io::Error, EnumError::Multiple);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<Vec<io::Error>> for EnumError {
+///    fn from(errors: Vec<io::Error>) -> Self {
+///        EnumError::Multiple(errors.iter().map(|err| err.to_string()).collect())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_aggregate {
+    ($enum_error:ident, $err_type: path, $enum_variant: path) => {
+        impl From<$crate::__private::Vec<$err_type>> for $enum_error {
+            fn from(errors: $crate::__private::Vec<$err_type>) -> $enum_error {
+                $enum_variant(errors.iter().map($crate::__private::ToString::to_string).collect())
+            }
+        }
+    };
+}
+
+/// Implement the `kind` and `message` accessors for an struct with kind structure, where the kind
+/// is returned by copy.
+///
+/// The kind must implement [`Copy`]; otherwise use [`implement_error_accessors_ref!`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_accessors_copy!($struct_error, $kind_type);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_accessors_copy;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement StructError::kind() and StructError::message().
+/// implement_error_accessors_copy!(StructError, ErrorKind);
+///
+/// let _: fn(&StructError) -> ErrorKind = StructError::kind;
+/// let _: fn(&StructError) -> &str = StructError::message;
+///
+/// let err = StructError { kind: ErrorKind::IO, message: "oh no!".to_string() };
+/// assert_eq!(err.kind(), ErrorKind::IO);
+/// assert_eq!(err.message(), "oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_accessors_copy!(StructError, ErrorKind);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///     pub fn kind(&self) -> ErrorKind {
+///         self.kind
+///     }
+///
+///     pub fn message(&self) -> &str {
+///         &self.message
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_accessors_copy {
+    ($struct_error:path, $kind_type: ty) => {
+        impl $struct_error {
+            pub fn kind(&self) -> $kind_type {
+                self.kind
+            }
+
+            pub fn message(&self) -> &str {
+                &self.message
+            }
+        }
+    };
+}
+
+/// Implement the `kind` and `message` accessors for an struct with kind structure, where the kind
+/// is returned by reference.
+///
+/// If the kind implements [`Copy`], you might prefer [`implement_error_accessors_copy!`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_accessors_ref!($struct_error, $kind_type);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_accessors_ref;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Http(String),
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement StructError::kind() and StructError::message().
+/// implement_error_accessors_ref!(StructError, ErrorKind);
+///
+/// let _: fn(&StructError) -> &ErrorKind = StructError::kind;
+/// let _: fn(&StructError) -> &str = StructError::message;
+///
+/// let err = StructError { kind: ErrorKind::Http("GET".to_string()), message: "oh no!".to_string() };
+/// assert_eq!(err.kind(), &ErrorKind::Http("GET".to_string()));
+/// assert_eq!(err.message(), "oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_accessors_ref!(StructError, ErrorKind);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///     pub fn kind(&self) -> &ErrorKind {
+///         &self.kind
+///     }
+///
+///     pub fn message(&self) -> &str {
+///         &self.message
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_accessors_ref {
+    ($struct_error:path, $kind_type: ty) => {
+        impl $struct_error {
+            pub fn kind(&self) -> &$kind_type {
+                &self.kind
+            }
+
+            pub fn message(&self) -> &str {
+                &self.message
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, only when a `cfg` predicate
+/// holds.
+///
+/// The predicate is passed verbatim to a `#[cfg(...)]` attribute, so it is evaluated in the crate
+/// calling the macro. This is useful for error types of optional dependencies.
+///
+/// # Params
+/// ```ignore
+/// implement_error_cfg!($predicate, $struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_cfg;
+/// use std::env::VarError;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<reqwest::Error> for StructError, only with the http feature.
+/// implement_error_cfg!(feature = "http", StructError, reqwest::Error, ErrorKind::IO);
+///
+/// // Never implemented, so the manual implementation below does not conflict.
+/// implement_error_cfg!(any(), StructError, io::Error, ErrorKind::IO);
+///
+/// impl From<io::Error> for StructError {
+///     fn from(err: io::Error) -> Self {
+///         Self { kind: ErrorKind::IO, message: format!("manual: {err}") }
+///     }
+/// }
+///
+/// // Always implemented.
+/// implement_error_cfg!(all(), StructError, VarError, ErrorKind::Var);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err.message, "manual: oh no!");
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.kind, ErrorKind::Var);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_cfg!(feature = "http", StructError, reqwest::Error, ErrorKind::Http);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// #[cfg(feature = "http")]
+/// impl From<reqwest::Error> for StructError {
+///    fn from(err: reqwest::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::Http,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_cfg {
+    ($predicate: meta, $struct_error:path, $error_type: path, $error_kind: path) => {
+        #[cfg($predicate)]
+        $crate::implement_error!($struct_error, $error_type, $error_kind);
+    };
+}
+
+/// Implement the [`From`] trait for many errors in an enum with a catch-all variant, boxing each
+/// error into the variant.
+///
+/// The variant must hold a `Box<dyn Error>`, with or without [`Send`] and [`Sync`].
+///
+/// # Params
+/// ```ignore
+/// implement_boxed_variant_for!($enum_error, $enum_variant, [$err_type, ...]);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_boxed_variant_for;
+/// use std::env::VarError;
+/// use std::error::Error;
+/// use std::io;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// pub enum EnumError {
+///     Other(Box<dyn Error>)
+/// }
+///
+/// // Implement From<io::Error>, From<VarError> and From<ParseIntError> for EnumError.
+/// implement_boxed_variant_for!(EnumError, EnumError::Other, [io::Error, VarError, ParseIntError]);
+///
+/// let EnumError::Other(err) = EnumError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert!(err.is::<io::Error>());
+///
+/// let EnumError::Other(err) = EnumError::from(VarError::NotPresent);
+/// assert!(err.is::<VarError>());
+///
+/// let EnumError::Other(err) = EnumError::from("heimdall".parse::<u8>().unwrap_err());
+/// assert!(err.is::<ParseIntError>());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_boxed_variant_for!(EnumError, EnumError::Other, [io::Error]);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for EnumError {
+///    fn from(err: io::Error) -> Self {
+///        EnumError::Other(Box::new(err))
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_boxed_variant_for {
+    ($enum_error:ident, $enum_variant: path, [$($err_type: path),* $(,)?]) => {
+        $(
+            impl From<$err_type> for $enum_error {
+                fn from(error: $err_type) -> $enum_error {
+                    $enum_variant($crate::__private::Box::new(error))
+                }
+            }
+        )*
+    };
+}
+
+/// Implement the [`Display`](std::fmt::Display) trait for an struct with kind, message and source
+/// attributes, falling back to the source or the kind when the message is empty.
+///
+/// The kind must implement [ToString], and the source attribute must be an
+/// `Option<Box<dyn Error>>`, with or without [`Send`] and [`Sync`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_display_fallback!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_display_fallback;
+/// use std::fmt::{Display, Formatter};
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// impl Display for ErrorKind {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "IO")
+///     }
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error>>
+/// }
+///
+/// // Implement Display for StructError.
+/// implement_error_display_fallback!(StructError);
+///
+/// let err = StructError {
+///     kind: ErrorKind::IO,
+///     message: String::new(),
+///     source: Some(Box::new(io::Error::new(io::ErrorKind::Other, "disk failure"))),
+/// };
+/// assert_eq!(err.to_string(), "disk failure");
+///
+/// let err = StructError { kind: ErrorKind::IO, message: String::new(), source: None };
+/// assert_eq!(err.to_string(), "IO");
+///
+/// let err = StructError { kind: ErrorKind::IO, message: "oh no!".to_string(), source: None };
+/// assert_eq!(err.to_string(), "oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_display_fallback!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Display for StructError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         if !self.message.is_empty() {
+///             Display::fmt(&self.message, f)
+///         } else if let Some(source) = &self.source {
+///             Display::fmt(source, f)
+///         } else {
+///             f.write_str(&self.kind.to_string())
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_display_fallback {
+    ($struct_error:path) => {
+        impl $crate::__private::fmt::Display for $struct_error {
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter<'_>) -> $crate::__private::fmt::Result {
+                if !self.message.is_empty() {
+                    $crate::__private::fmt::Display::fmt(&self.message, f)
+                } else if let Some(source) = &self.source {
+                    $crate::__private::fmt::Display::fmt(source, f)
+                } else {
+                    f.write_str(&$crate::__private::ToString::to_string(&self.kind))
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`Termination`](std::process::Termination) trait for an error, mapping it to an
+/// exit code with a closure.
+///
+/// Available with the `std` feature. The closure receives a reference to the error and returns
+/// the exit code:
+/// ```ignore
+/// |error: &$error| -> u8
+/// ```
+///
+/// The implementation only applies when `main` returns the error itself. When `main` returns a
+/// `Result<(), $error>`, the standard library exits with code 1 for any error, so return an
+/// [`ExitCode`](std::process::ExitCode) built with `report` instead:
+/// ```ignore
+/// fn main() -> ExitCode {
+///     match run() {
+///         Ok(()) => ExitCode::SUCCESS,
+///         Err(err) => err.report(),
+///     }
+/// }
+/// ```
+///
+/// # Params
+/// ```ignore
+/// implement_termination!($error, $exit_code);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_termination;
+/// use std::process::{ExitCode, Termination};
+///
+/// pub enum EnumError {
+///     NotFound,
+///     Other,
+/// }
+///
+/// // Implement Termination for EnumError.
+/// implement_termination!(EnumError, |err| match err {
+///     EnumError::NotFound => 2,
+///     _ => 1,
+/// });
+///
+/// assert!(EnumError::NotFound.report() == ExitCode::from(2));
+/// assert!(EnumError::Other.report() == ExitCode::from(1));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_termination!(EnumError, exit_code);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Termination for EnumError {
+///    fn report(self) -> ExitCode {
+///        ExitCode::from(exit_code(&self))
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! implement_termination {
+    ($error:path, $exit_code: expr) => {
+        impl $crate::__private::Termination for $error {
+            fn report(self) -> $crate::__private::ExitCode {
+                let exit_code = $crate::__private::by_ref::<$error, u8, _>($exit_code);
+                $crate::__private::ExitCode::from(exit_code(&self))
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an error, building it entirely with a closure.
+///
+/// The closure receives the error by value and returns the built error:
+/// ```ignore
+/// |error: $error_type| -> $struct_error
+/// ```
+///
+/// # Params
+/// ```ignore
+/// implement_error_custom!($struct_error, $error_type, $convert);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_custom;
+/// use std::error::Error;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     NotFound,
+///     IO,
+/// }
+///
+/// fn compute(err: &io::Error) -> ErrorKind {
+///     match err.kind() {
+///         io::ErrorKind::NotFound => ErrorKind::NotFound,
+///         _ => ErrorKind::IO,
+///     }
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn Error>>
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_custom!(StructError, io::Error, |e| StructError {
+///     kind: compute(&e),
+///     message: e.to_string(),
+///     source: Some(Box::new(e)),
+/// });
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.kind, ErrorKind::NotFound);
+/// assert!(err.source.unwrap().is::<io::Error>());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_custom!(StructError, io::Error, convert);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        convert(err)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_custom {
+    ($struct_error:path, $error_type: path, $convert: expr) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let convert = $crate::__private::by_value::<$error_type, $struct_error, _>($convert);
+                convert(error)
+            }
+        }
+    };
+}
+
+/// Generate the [From<T>] trait implementation for an custom enum error using [ToString] trait,
+/// prefixing the message with a context.
+///
+/// # Params
+/// ```ignore
+///     implement_string_error_in_enum_ctx!($enum_error, $err_type, $enum_variant, $context);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_string_error_in_enum_ctx;
+///
+/// pub enum EnumError {
+///     IO(String)
+/// }
+///
+/// // Implement From<std::io::Error> for EnumError.
+/// implement_string_error_in_enum_ctx!(EnumError, std::io::Error, EnumError::IO, "I/O failure");
+///
+/// let EnumError::IO(message) = EnumError::from(std::io::Error::other("oh no!"));
+/// assert!(message.starts_with("I/O failure:"));
+/// assert_eq!(message, "I/O failure: oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_string_error_in_enum_ctx!(EnumError, std::io::Error, EnumError::IO, "I/O failure");
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<std::io::Error> for EnumError {
+///    fn from(err: std::io::Error) -> Self {
+///        EnumError::IO(format!("{}: {}", "I/O failure", err))
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_string_error_in_enum_ctx {
+    ($enum_error:ident, $err_type: path, $enum_variant: path, $context: literal) => {
+        impl From<$err_type> for $enum_error {
+            fn from(error: $err_type) -> $enum_error {
+                $enum_variant($crate::__private::format!("{}: {}", $context, error))
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for boxed errors in an enum, downcasting the boxed error into the
+/// variant of its concrete type.
+///
+/// The error types are tried in order, and the box is moved into the fallback variant when none
+/// of them matches. The fallback variant must hold a `Box<dyn Error>`.
+///
+/// # Params
+/// ```ignore
+/// implement_downcast_from_box!($enum_error, [$err_type => $enum_variant, ...], $fallback_variant);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_downcast_from_box;
+/// use std::env::VarError;
+/// use std::error::Error;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum EnumError {
+///     IO(io::Error),
+///     Var(VarError),
+///     Other(Box<dyn Error>),
+/// }
+///
+/// // Implement From<Box<dyn Error>> for EnumError.
+/// implement_downcast_from_box!(EnumError, [
+///     io::Error => EnumError::IO,
+///     VarError => EnumError::Var,
+/// ], EnumError::Other);
+///
+/// let boxed: Box<dyn Error> = Box::new(io::Error::from(io::ErrorKind::NotFound));
+/// assert!(matches!(EnumError::from(boxed), EnumError::IO(_)));
+///
+/// let boxed: Box<dyn Error> = Box::new(VarError::NotPresent);
+/// assert!(matches!(EnumError::from(boxed), EnumError::Var(VarError::NotPresent)));
+///
+/// let boxed: Box<dyn Error> = Box::new("heimdall".parse::<u8>().unwrap_err());
+/// assert!(matches!(EnumError::from(boxed), EnumError::Other(_)));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_downcast_from_box!(EnumError, [io::Error => EnumError::IO], EnumError::Other);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<Box<dyn Error>> for EnumError {
+///    fn from(err: Box<dyn Error>) -> Self {
+///        let err = match err.downcast::<io::Error>() {
+///            Ok(err) => return EnumError::IO(*err),
+///            Err(err) => err,
+///        };
+///        EnumError::Other(err)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_downcast_from_box {
+    ($enum_error:ident, [$($err_type: path => $enum_variant: path),* $(,)?], $fallback_variant: path) => {
+        impl From<$crate::__private::Box<dyn $crate::__private::Error>> for $enum_error {
+            fn from(error: $crate::__private::Box<dyn $crate::__private::Error>) -> $enum_error {
+                $(
+                    let error = match error.downcast::<$err_type>() {
+                        Ok(error) => return $enum_variant(*error),
+                        Err(error) => error,
+                    };
+                )*
+                $fallback_variant(error)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for [`Infallible`](std::convert::Infallible) in an error.
+///
+/// The conversion can never happen, but it satisfies `From<Infallible>` bounds of generic code.
+///
+/// # Params
+/// ```ignore
+/// implement_infallible!($error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_infallible;
+/// use std::convert::Infallible;
+///
+/// pub struct StructError {
+///     message: String,
+/// }
+///
+/// // Implement From<Infallible> for StructError.
+/// implement_infallible!(StructError);
+///
+/// fn convert<E: From<Infallible>>(result: Result<u8, Infallible>) -> Result<u8, E> {
+///     Ok(result?)
+/// }
+///
+/// assert!(convert::<StructError>(Ok(1)).is_ok());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_infallible!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<Infallible> for StructError {
+///    fn from(err: Infallible) -> Self {
+///        match err {}
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_infallible {
+    ($error:path) => {
+        impl From<$crate::__private::Infallible> for $error {
+            fn from(error: $crate::__private::Infallible) -> $error {
+                match error {}
+            }
+        }
+    };
+}
+
+/// Implement a constructor for an struct with kind structure, for values that are absent.
+///
+/// The constructor is named `missing` unless a name is given, and it is useful with
+/// [`Option::ok_or_else`].
+///
+/// # Params
+/// ```ignore
+/// implement_from_none!($struct_error, $error_kind, $message);
+/// implement_from_none!($struct_error, $error_kind, $message, $ctor);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_from_none;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Missing,
+///     MissingUser,
+/// }
+///
+/// #[derive(Debug)]
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement StructError::missing() and StructError::missing_user().
+/// implement_from_none!(StructError, ErrorKind::Missing, "value was absent");
+/// implement_from_none!(StructError, ErrorKind::MissingUser, "user was absent", missing_user);
+///
+/// let err = None::<u8>.ok_or_else(StructError::missing).unwrap_err();
+/// assert_eq!(err.kind, ErrorKind::Missing);
+/// assert_eq!(err.message, "value was absent");
+///
+/// let err = StructError::missing_user();
+/// assert_eq!(err.kind, ErrorKind::MissingUser);
+///
+/// // The message can be any expression implementing Display, like a String.
+/// implement_from_none!(StructError, ErrorKind::Missing, format!("{} was absent", "config"), missing_config);
+///
+/// let err = StructError::missing_config();
+/// assert_eq!(err.message, "config was absent");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_from_none!(StructError, ErrorKind::Missing, "value was absent");
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///    pub fn missing() -> Self {
+///        Self {
+///            kind: ErrorKind::Missing,
+///            message: "value was absent".to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_from_none {
+    ($struct_error:path, $error_kind: path, $message: expr) => {
+        $crate::implement_from_none!($struct_error, $error_kind, $message, missing);
+    };
+    ($struct_error:path, $error_kind: path, $message: expr, $ctor: ident) => {
+        impl $struct_error {
+            pub fn $ctor() -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&$message),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for [`std::io::Error`] in an struct with kind structure and a
+/// `raw_os_error: Option<i32>` attribute.
+///
+/// Available with the `std` feature. The raw OS error is the one returned by
+/// [`std::io::Error::raw_os_error`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_os_code!($struct_error, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_os_code;
+/// use std::{env, fs};
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     raw_os_error: Option<i32>,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_os_code!(StructError, ErrorKind::IO);
+///
+/// fn foo() -> Result<String, StructError> {
+///     let mut path = env::temp_dir();
+///     path.push("inexist.file.ñ");
+///     Ok(fs::read_to_string(path)?)
+/// }
+///
+/// let err = foo().unwrap_err();
+/// assert!(err.raw_os_error.is_some());
+/// #[cfg(unix)]
+/// assert_eq!(err.raw_os_error, Some(2));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_os_code!(StructError, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            raw_os_error: err.raw_os_error(),
+///        }
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! implement_error_with_os_code {
+    ($struct_error:path, $error_kind: path) => {
+        impl From<$crate::__private::io::Error> for $struct_error {
+            fn from(error: $crate::__private::io::Error) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    raw_os_error: error.raw_os_error(),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, logging an error event on each
+/// conversion.
+///
+/// Available with the `tracing` feature, which is the only one that makes the crate depend on
+/// [tracing]. The event has a `kind` field with the kind, and the message of the error as message.
+///
+/// # Params
+/// ```ignore
+/// implement_error_logged!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_logged;
+/// use std::fmt::Debug;
+/// use std::io;
+/// use std::sync::{Arc, Mutex};
+/// use tracing::field::{Field, Visit};
+/// use tracing::span::{Attributes, Id, Record};
+/// use tracing::{Event, Metadata, Subscriber};
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_logged!(StructError, io::Error, ErrorKind::IO);
+///
+/// // Subscriber capturing the fields of the events.
+/// #[derive(Clone, Default)]
+/// struct Capture(Arc<Mutex<Vec<String>>>);
+///
+/// impl Visit for Capture {
+///     fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+///         self.0.lock().unwrap().push(format!("{}={:?}", field.name(), value));
+///     }
+/// }
+///
+/// impl Subscriber for Capture {
+///     fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+///     fn new_span(&self, _: &Attributes<'_>) -> Id { Id::from_u64(1) }
+///     fn record(&self, _: &Id, _: &Record<'_>) {}
+///     fn record_follows_from(&self, _: &Id, _: &Id) {}
+///     fn event(&self, event: &Event<'_>) { event.record(&mut self.clone()) }
+///     fn enter(&self, _: &Id) {}
+///     fn exit(&self, _: &Id) {}
+/// }
+///
+/// let capture = Capture::default();
+/// tracing::subscriber::with_default(capture.clone(), || {
+///     StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"))
+/// });
+///
+/// let fields = capture.0.lock().unwrap();
+/// assert_eq!(*fields, ["message=oh no!", "kind=\"ErrorKind::IO\""]);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_logged!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        let message = err.to_string();
+///        tracing::error!(kind = "ErrorKind::IO", "{}", message);
+///        Self {
+///            kind: ErrorKind::IO,
+///            message,
+///        }
+///     }
+/// }
+/// ```
+///
+/// [tracing]:https://crates.io/crates/tracing
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! implement_error_logged {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let message = $crate::__private::ToString::to_string(&error);
+                $crate::__private::tracing::error!(kind = stringify!($error_kind), "{}", message);
+                $struct_error {
+                    kind: $error_kind,
+                    message,
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait between two enums, mapping each variant of the source enum to a
+/// variant of the target enum.
+///
+/// The variants must hold a single value of the same type, and the mapping must list every variant
+/// of the source enum.
+///
+/// # Params
+/// ```ignore
+/// implement_enum_from_enum!($enum_error, $sub_error, [$sub_variant => $enum_variant, ...]);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_enum_from_enum;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum SubError {
+///     IO(io::Error),
+///     Net(String),
+/// }
+///
+/// #[derive(Debug)]
+/// pub enum TopError {
+///     IO(io::Error),
+///     Net(String),
+/// }
+///
+/// // Implement From<SubError> for TopError.
+/// implement_enum_from_enum!(TopError, SubError, [
+///     SubError::IO => TopError::IO,
+///     SubError::Net => TopError::Net,
+/// ]);
+///
+/// let err = TopError::from(SubError::IO(io::Error::from(io::ErrorKind::NotFound)));
+/// assert!(matches!(err, TopError::IO(_)));
+///
+/// let err = TopError::from(SubError::Net("timeout".to_string()));
+/// assert!(matches!(err, TopError::Net(message) if message == "timeout"));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_enum_from_enum!(TopError, SubError, [SubError::IO => TopError::IO]);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<SubError> for TopError {
+///    fn from(err: SubError) -> Self {
+///        match err {
+///            SubError::IO(err) => TopError::IO(err),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_enum_from_enum {
+    ($enum_error:ident, $sub_error: path, [$($sub_variant: path => $enum_variant: path),* $(,)?]) => {
+        impl From<$sub_error> for $enum_error {
+            fn from(error: $sub_error) -> $enum_error {
+                match error {
+                    $($sub_variant(error) => $enum_variant(error),)*
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, recording the time of the
+/// conversion in a `created_at: SystemTime` attribute.
+///
+/// Available with the `std` feature. The name of the attribute is always `created_at`.
+///
+/// Expands into [`implement_error_extra!`] with a `created_at` attribute.
+///
+/// # Params
+/// ```ignore
+/// implement_error_timestamped!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_timestamped;
+/// use std::io;
+/// use std::time::{Duration, SystemTime};
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     created_at: SystemTime,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_timestamped!(StructError, io::Error, ErrorKind::IO);
+///
+/// let before = SystemTime::now();
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+///
+/// let elapsed = err.created_at.duration_since(before).unwrap();
+/// assert!(elapsed < Duration::from_secs(1));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_timestamped!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            created_at: SystemTime::now(),
+///        }
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! implement_error_timestamped {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        $crate::implement_error_extra!($struct_error, $error_type, $error_kind, {
+            created_at: $crate::__private::SystemTime::now()
+        });
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, truncating the message to a
+/// maximum number of characters.
+///
+/// The limit counts chars, not bytes, so multi-byte characters are never split. When the message
+/// is truncated, an ellipsis (`…`) is appended after the first `$max_chars` characters.
+///
+/// # Params
+/// ```ignore
+/// implement_error_truncated!($struct_error, $error_type, $error_kind, $max_chars);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_truncated;
+/// use std::fmt;
+///
+/// pub enum ErrorKind {
+///     Query,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// #[derive(Debug)]
+/// pub struct QueryError(String);
+///
+/// impl fmt::Display for QueryError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// // Implement From<QueryError> for StructError, keeping at most 256 chars of the message.
+/// implement_error_truncated!(StructError, QueryError, ErrorKind::Query, 256);
+///
+/// let err = StructError::from(QueryError("é".repeat(1000)));
+/// assert_eq!(err.message.chars().count(), 257);
+/// assert!(err.message.ends_with('…'));
+///
+/// let err = StructError::from(QueryError("short".to_string()));
+/// assert_eq!(err.message, "short");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_truncated!(StructError, QueryError, ErrorKind::Query, 256);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<QueryError> for StructError {
+///    fn from(err: QueryError) -> Self {
+///        let mut message = err.to_string();
+///        if let Some((index, _)) = message.char_indices().nth(256) {
+///            message.truncate(index);
+///            message.push('…');
+///        }
+///
+///        Self {
+///            kind: ErrorKind::Query,
+///            message,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_truncated {
+    ($struct_error:path, $error_type: path, $error_kind: path, $max_chars: expr) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let mut message = $crate::__private::ToString::to_string(&error);
+                if let Some((index, _)) = message.char_indices().nth($max_chars) {
+                    message.truncate(index);
+                    message.push('…');
+                }
+
+                $struct_error {
+                    kind: $error_kind,
+                    message,
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, from any
+/// [`PoisonError<T>`](std::sync::PoisonError).
+///
+/// Available with the `std` feature. Only the message is kept; the guard carried by the poison
+/// error is dropped during the conversion, so the struct does not borrow the lock.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_poison!($struct_error, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_poison;
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Lock,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<PoisonError<T>> for StructError, for every T.
+/// implement_error_from_poison!(StructError, ErrorKind::Lock);
+///
+/// fn read(counter: &Mutex<u32>) -> Result<u32, StructError> {
+///     Ok(*counter.lock()?)
+/// }
+///
+/// let counter = Arc::new(Mutex::new(0));
+/// let poisoner = Arc::clone(&counter);
+/// let _ = thread::spawn(move || {
+///     let _guard = poisoner.lock().unwrap();
+///     panic!("poison the mutex");
+/// })
+/// .join();
+///
+/// let err = read(&counter).unwrap_err();
+/// assert_eq!(err.kind, ErrorKind::Lock);
+/// assert_eq!(err.message, "poisoned lock: another task failed inside");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_poison!(StructError, ErrorKind::Lock);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl<T> From<PoisonError<T>> for StructError {
+///    fn from(err: PoisonError<T>) -> Self {
+///        Self {
+///            kind: ErrorKind::Lock,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! implement_error_from_poison {
+    ($struct_error:path, $error_kind: path) => {
+        impl<T> From<$crate::__private::PoisonError<T>> for $struct_error {
+            fn from(error: $crate::__private::PoisonError<T>) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, with a `retryable: bool`
+/// attribute telling whether the failed operation is worth retrying.
+///
+/// Expands into [`implement_error_extra!`] with a `retryable` attribute.
+///
+/// # Params
+/// ```ignore
+/// implement_error_retryable!($struct_error, $error_type, $error_kind, $retryable);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_retryable;
+/// use std::io;
+/// use std::num::ParseIntError;
+///
+/// pub enum ErrorKind {
+///     IO,
+///     Parse,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     retryable: bool,
+/// }
+///
+/// // A failed read may succeed later, a bad number never will.
+/// implement_error_retryable!(StructError, io::Error, ErrorKind::IO, true);
+/// implement_error_retryable!(StructError, ParseIntError, ErrorKind::Parse, false);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::TimedOut));
+/// assert!(err.retryable);
+///
+/// let err = StructError::from("x".parse::<u8>().unwrap_err());
+/// assert!(!err.retryable);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_retryable!(StructError, io::Error, ErrorKind::IO, true);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            retryable: true,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_retryable {
+    ($struct_error:path, $error_type: path, $error_kind: path, $retryable: expr) => {
+        $crate::implement_error_extra!($struct_error, $error_type, $error_kind, { retryable: $retryable });
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, from a bare message.
+///
+/// Both `From<&'static str>` and `From<String>` are generated, and the kind is always
+/// `$error_kind`.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_message!($struct_error, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_message;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Generic,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<&'static str> and From<String> for StructError.
+/// implement_error_from_message!(StructError, ErrorKind::Generic);
+///
+/// let err = StructError::from("invalid configuration");
+/// assert_eq!(err.kind, ErrorKind::Generic);
+/// assert_eq!(err.message, "invalid configuration");
+///
+/// let err = StructError::from(format!("missing key {}", "port"));
+/// assert_eq!(err.kind, ErrorKind::Generic);
+/// assert_eq!(err.message, "missing key port");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_message!(StructError, ErrorKind::Generic);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<&'static str> for StructError {
+///    fn from(message: &'static str) -> Self {
+///        Self {
+///            kind: ErrorKind::Generic,
+///            message: message.to_string(),
+///        }
+///     }
+/// }
+///
+/// impl From<String> for StructError {
+///    fn from(message: String) -> Self {
+///        Self {
+///            kind: ErrorKind::Generic,
+///            message,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_from_message {
+    ($struct_error:path, $error_kind: path) => {
+        impl From<&'static str> for $struct_error {
+            fn from(message: &'static str) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(message),
+                }
+            }
+        }
+
+        impl From<$crate::__private::String> for $struct_error {
+            fn from(message: $crate::__private::String) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message,
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, capturing a
+/// [`Backtrace`](std::backtrace::Backtrace) in a `backtrace` attribute.
+///
+/// Available with the `std` feature. The backtrace is taken with `Backtrace::capture()`, so it is
+/// only collected when `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` enables it. `Backtrace` is stable
+/// since Rust 1.65, which the crate MSRV already covers.
+///
+/// Expands into [`implement_error_extra!`] with a `backtrace` attribute.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_backtrace!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_backtrace;
+/// use std::backtrace::{Backtrace, BacktraceStatus};
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     backtrace: Backtrace,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_backtrace!(StructError, io::Error, ErrorKind::IO);
+///
+/// // Enable backtraces before the first capture, whatever the environment says.
+/// std::env::remove_var("RUST_LIB_BACKTRACE");
+/// std::env::set_var("RUST_BACKTRACE", "1");
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.backtrace.status(), BacktraceStatus::Captured);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_backtrace!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            backtrace: Backtrace::capture(),
+///        }
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! implement_error_with_backtrace {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        $crate::implement_error_extra!($struct_error, $error_type, $error_kind, {
+            backtrace: $crate::__private::Backtrace::capture()
+        });
+    };
+}
+
+/// Implement the [`From`] trait for an custom enum error, from [`io::Error`](std::io::Error),
+/// choosing the variant from the [`io::ErrorKind`](std::io::ErrorKind) of the error.
+///
+/// Available with the `std` feature. Every variant carries the message of the error as a
+/// [String]. The `_` fallback arm is required and must be the last one.
+///
+/// # Params
+/// ```ignore
+/// implement_io_kind_dispatch!($enum_error, { $io_kind => $enum_variant, ..., _ => $fallback_variant });
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_io_kind_dispatch;
+/// use std::fs::File;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// pub enum EnumError {
+///     NotFound(String),
+///     Denied(String),
+///     IO(String),
+/// }
+///
+/// // Implement From<io::Error> for EnumError.
+/// implement_io_kind_dispatch!(EnumError, {
+///     NotFound => EnumError::NotFound,
+///     PermissionDenied => EnumError::Denied,
+///     _ => EnumError::IO,
+/// });
+///
+/// let err = EnumError::from(File::open("/heimdall/does/not/exist").unwrap_err());
+/// assert!(matches!(err, EnumError::NotFound(_)));
+///
+/// let err = EnumError::from(io::Error::from(io::ErrorKind::TimedOut));
+/// assert!(matches!(err, EnumError::IO(_)));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_io_kind_dispatch!(EnumError, {
+///     NotFound => EnumError::NotFound,
+///     PermissionDenied => EnumError::Denied,
+///     _ => EnumError::IO,
+/// });
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for EnumError {
+///    fn from(err: io::Error) -> Self {
+///        let message = err.to_string();
+///        match err.kind() {
+///            io::ErrorKind::NotFound => EnumError::NotFound(message),
+///            io::ErrorKind::PermissionDenied => EnumError::Denied(message),
+///            _ => EnumError::IO(message),
+///        }
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! implement_io_kind_dispatch {
+    ($enum_error:ident, { $($io_kind:ident => $enum_variant: path,)* _ => $fallback_variant: path $(,)? }) => {
+        impl From<$crate::__private::io::Error> for $enum_error {
+            fn from(error: $crate::__private::io::Error) -> $enum_error {
+                let message = $crate::__private::ToString::to_string(&error);
+                match error.kind() {
+                    $($crate::__private::io::ErrorKind::$io_kind => $enum_variant(message),)*
+                    _ => $fallback_variant(message),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, building the message from both
+/// the [`Display`](core::fmt::Display) and the [`Debug`](core::fmt::Debug) output of the error.
+///
+/// The message has the form `"{error} (debug: {error:?})"`, which keeps the details that some
+/// errors only show in their [`Debug`](core::fmt::Debug) output.
+///
+/// # Params
+/// ```ignore
+/// implement_error_rich_message!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_rich_message;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_rich_message!(StructError, io::Error, ErrorKind::IO);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "disk full"));
+/// assert!(err.message.starts_with("disk full"));
+/// assert!(err.message.contains("(debug: Custom"));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_rich_message!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: format!("{} (debug: {:?})", err, err),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_rich_message {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::format!("{} (debug: {:?})", error, error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, where the message is taken
+/// from the inner value returned by a method of the error.
+///
+/// The method is called without arguments, and its return value must implement
+/// [`Display`](core::fmt::Display). It may consume the error, like `into_inner`.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_inner!($struct_error, $error_type, $error_kind, $method);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_inner;
+/// use std::num::ParseIntError;
+///
+/// pub struct ConfigError {
+///     inner: ParseIntError,
+/// }
+///
+/// impl ConfigError {
+///     pub fn into_inner(self) -> ParseIntError {
+///         self.inner
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Config,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<ConfigError> for StructError.
+/// implement_error_from_inner!(StructError, ConfigError, ErrorKind::Config, into_inner);
+///
+/// let inner = "x".parse::<u8>().unwrap_err();
+/// let err = StructError::from(ConfigError { inner: inner.clone() });
+/// assert_eq!(err.kind, ErrorKind::Config);
+/// assert_eq!(err.message, inner.to_string());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_inner!(StructError, ConfigError, ErrorKind::Config, into_inner);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<ConfigError> for StructError {
+///    fn from(err: ConfigError) -> Self {
+///        Self {
+///            kind: ErrorKind::Config,
+///            message: err.into_inner().to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_from_inner {
+    ($struct_error:path, $error_type: path, $error_kind: path, $method: ident) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error.$method()),
+                }
+            }
+        }
+    };
+}
+
+/// Generate the [From<T>] trait implementation for an custom enum error using [ToString] trait,
+/// prefixing the message with a bracketed kind.
+///
+/// The message has the form `"[{kind}] {error}"`, so enums storing only strings keep a category
+/// marker. See [`implement_string_error_in_enum_ctx!`] for a `"{context}: {error}"` prefix.
+///
+/// # Params
+/// ```ignore
+///     implement_string_error_with_kind_prefix!($enum_error, $err_type, $enum_variant, $kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_string_error_with_kind_prefix;
+/// use std::io;
+///
+/// pub enum EnumError {
+///     Message(String)
+/// }
+///
+/// // Implement From<io::Error> for EnumError.
+/// implement_string_error_with_kind_prefix!(EnumError, io::Error, EnumError::Message, "IO");
+///
+/// let EnumError::Message(message) = EnumError::from(io::Error::other("oh no!"));
+/// assert_eq!(message, "[IO] oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_string_error_with_kind_prefix!(EnumError, io::Error, EnumError::Message, "IO");
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for EnumError {
+///    fn from(err: io::Error) -> Self {
+///        EnumError::Message(format!("[{}] {}", "IO", err))
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_string_error_with_kind_prefix {
+    ($enum_error:ident, $err_type: path, $enum_variant: path, $kind: literal) => {
+        impl From<$err_type> for $enum_error {
+            fn from(error: $err_type) -> $enum_error {
+                $enum_variant($crate::__private::format!("[{}] {}", $kind, error))
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for a tuple struct with kind, message and code positions.
+///
+/// The first position of the tuple struct is the kind, the second one is the message, and the
+/// third one is the code.
+///
+/// # Params
+/// ```ignore
+/// implement_tuple_error_with_code!($struct_error, $error_type, $error_kind, $code);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_tuple_error_with_code;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct TupleError(ErrorKind, String, u32);
+///
+/// // Implement From<io::Error> for TupleError.
+/// implement_tuple_error_with_code!(TupleError, io::Error, ErrorKind::IO, 500);
+///
+/// let err = TupleError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err.0, ErrorKind::IO);
+/// assert_eq!(err.1, "oh no!");
+/// assert_eq!(err.2, 500);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_tuple_error_with_code!(TupleError, io::Error, ErrorKind::IO, 500);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for TupleError {
+///    fn from(err: io::Error) -> Self {
+///        TupleError(ErrorKind::IO, err.to_string(), 500)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_tuple_error_with_code {
+    ($struct_error:path, $error_type: path, $error_kind: path, $code: expr) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error($error_kind, $crate::__private::ToString::to_string(&error), $code)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an error implementing [`HeimdallError`].
+///
+/// The error is built with [`HeimdallError::from_parts`], so the names of its fields don't
+/// matter.
+///
+/// # Params
+/// ```ignore
+/// implement_error_via_trait!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error_via_trait, HeimdallError};
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     category: ErrorKind,
+///     detail: String,
+/// }
+///
+/// impl HeimdallError for StructError {
+///     type Kind = ErrorKind;
+///
+///     fn from_parts(kind: ErrorKind, message: String) -> Self {
+///         Self {
+///             category: kind,
+///             detail: message,
+///         }
+///     }
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_via_trait!(StructError, io::Error, ErrorKind::IO);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err.category, ErrorKind::IO);
+/// assert_eq!(err.detail, "oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_via_trait!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        <StructError as HeimdallError>::from_parts(ErrorKind::IO, err.to_string())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_via_trait {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                <$struct_error as $crate::HeimdallError>::from_parts(
+                    $error_kind,
+                    $crate::__private::ToString::to_string(&error),
+                )
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, incrementing a [metrics]
+/// counter on every conversion.
+///
+/// Available with the `metrics` feature. The counter is labeled with the kind variant name, so
+/// `ErrorKind::IO` gives `"kind" => "IO"`.
+///
+/// # Params
+/// ```ignore
+/// implement_error_metered!($struct_error, $error_type, $error_kind, $counter_name);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_metered;
+/// use metrics::{Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+/// use std::io;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::sync::{Arc, Mutex};
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_metered!(StructError, io::Error, ErrorKind::IO, "errors_total");
+///
+/// // Recorder keeping the keys and values of the counters.
+/// #[derive(Default)]
+/// struct Capture(Mutex<Vec<(Key, Arc<Total>)>>);
+///
+/// #[derive(Default)]
+/// struct Total(AtomicU64);
+///
+/// impl CounterFn for Total {
+///     fn increment(&self, value: u64) { self.0.fetch_add(value, Ordering::Relaxed); }
+///     fn absolute(&self, value: u64) { self.0.store(value, Ordering::Relaxed); }
+/// }
+///
+/// impl Recorder for Capture {
+///     fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+///     fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+///     fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+///     fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+///         let mut counters = self.0.lock().unwrap();
+///         let total = match counters.iter().find(|(k, _)| k == key) {
+///             Some((_, total)) => Arc::clone(total),
+///             None => {
+///                 let total = Arc::new(Total::default());
+///                 counters.push((key.clone(), Arc::clone(&total)));
+///                 total
+///             }
+///         };
+///         Counter::from_arc(total)
+///     }
+///     fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge { Gauge::noop() }
+///     fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram { Histogram::noop() }
+/// }
+///
+/// let capture = Capture::default();
+/// metrics::with_local_recorder(&capture, || {
+///     StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+///     StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// });
+///
+/// let counters = capture.0.lock().unwrap();
+/// let (key, total) = &counters[0];
+/// assert_eq!(key.name(), "errors_total");
+/// assert_eq!(key.labels().next().unwrap().value(), "IO");
+/// assert_eq!(total.0.load(Ordering::Relaxed), 2);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_metered!(StructError, io::Error, ErrorKind::IO, "errors_total");
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        metrics::counter!("errors_total", "kind" => "IO").increment(1);
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+///
+/// [metrics]:https://crates.io/crates/metrics
+#[cfg(feature = "metrics")]
+#[macro_export]
+macro_rules! implement_error_metered {
+    ($struct_error:path, $error_type: path, $error_kind: path, $counter_name: literal) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let kind = $crate::__private::type_name(stringify!($error_kind));
+                $crate::__private::metrics::counter!($counter_name, "kind" => kind).increment(1);
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for arrays of errors of any length in an enum with a variant
+/// aggregating their messages.
+///
+/// The last param names the const generic length of the array. See
+/// [`implement_error_aggregate!`] for a [Vec] of errors.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_array!($enum_error, $err_type, $enum_variant, $len);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_array;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum EnumError {
+///     Multiple(Vec<String>)
+/// }
+///
+/// // Implement From<[io::Error; N]> for EnumError, for every N.
+/// implement_error_from_array!(EnumError, io::Error, EnumError::Multiple, N);
+///
+/// let errors = [
+///     io::Error::new(io::ErrorKind::Other, "first"),
+///     io::Error::new(io::ErrorKind::Other, "second"),
+/// ];
+/// let err = EnumError::from(errors);
+/// assert_eq!(err, EnumError::Multiple(vec!["first".to_string(), "second".to_string()]));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_array!(EnumError, io::Error, EnumError::Multiple, N);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl<const N: usize> From<[io::Error; N]> for EnumError {
+///    fn from(errors: [io::Error; N]) -> Self {
+///        EnumError::Multiple(errors.iter().map(|err| err.to_string()).collect())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_from_array {
+    ($enum_error:ident, $err_type: path, $enum_variant: path, $len: ident) => {
+        impl<const $len: usize> From<[$err_type; $len]> for $enum_error {
+            fn from(errors: [$err_type; $len]) -> $enum_error {
+                $enum_variant(errors.iter().map($crate::__private::ToString::to_string).collect())
+            }
+        }
+    };
+}
+
+/// Implement a `tag` method for an struct with kind structure, returning a stable string for
+/// each kind.
+///
+/// The tag is independent of the name of the variant, so renaming a kind doesn't change the
+/// serialized value. The match must cover every kind.
+///
+/// # Params
+/// ```ignore
+/// implement_error_tag!($struct_error, { $kind => $tag, ... });
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_tag;
+///
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement StructError::tag().
+/// implement_error_tag!(StructError, {
+///     ErrorKind::IO => "io_error",
+///     ErrorKind::Var => "env_error",
+/// });
+///
+/// let err = StructError { kind: ErrorKind::IO, message: String::new() };
+/// assert_eq!(err.tag(), "io_error");
+///
+/// let err = StructError { kind: ErrorKind::Var, message: String::new() };
+/// assert_eq!(err.tag(), "env_error");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_tag!(StructError, { ErrorKind::IO => "io_error", ErrorKind::Var => "env_error" });
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///     pub fn tag(&self) -> &'static str {
+///         match &self.kind {
+///             ErrorKind::IO => "io_error",
+///             ErrorKind::Var => "env_error",
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_tag {
+    ($struct_error:path, { $($kind: pat => $tag: literal),* $(,)? }) => {
+        impl $struct_error {
+            pub fn tag(&self) -> &'static str {
+                match &self.kind {
+                    $($kind => $tag,)*
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, with a
+/// `visibility: Visibility` attribute telling whether the error can be shown to users.
+///
+/// The last param is the variant of [`Visibility`]: `Internal` or `Public`.
+///
+/// Expands into [`implement_error_extra!`] with a `visibility` attribute.
+///
+/// # Params
+/// ```ignore
+/// implement_error_visibility!($struct_error, $error_type, $error_kind, $visibility);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error_visibility, Visibility};
+/// use std::io;
+/// use std::num::ParseIntError;
+///
+/// pub enum ErrorKind {
+///     IO,
+///     Parse,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     visibility: Visibility,
+/// }
+///
+/// // I/O failures stay internal, while parse errors are the user's input.
+/// implement_error_visibility!(StructError, io::Error, ErrorKind::IO, Internal);
+/// implement_error_visibility!(StructError, ParseIntError, ErrorKind::Parse, Public);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.visibility, Visibility::Internal);
+///
+/// let err = StructError::from("x".parse::<u8>().unwrap_err());
+/// assert_eq!(err.visibility, Visibility::Public);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_visibility!(StructError, io::Error, ErrorKind::IO, Internal);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            visibility: Visibility::Internal,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_visibility {
+    ($struct_error:path, $error_type: path, $error_kind: path, $visibility: ident) => {
+        $crate::implement_error_extra!($struct_error, $error_type, $error_kind, {
+            visibility: $crate::Visibility::$visibility
+        });
+    };
+}
+
+/// Implement the [`Display`](std::fmt::Display) trait for an struct with kind structure, hiding
+/// the message.
+///
+/// Only the kind is printed, followed by `(details hidden)`, so messages that may contain
+/// secrets never reach the logs. The kind is printed using its [ToString] implementation.
+///
+/// # Params
+/// ```ignore
+/// implement_error_redacted_display!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_redacted_display;
+/// use std::fmt::{Display, Formatter};
+///
+/// pub enum ErrorKind {
+///     Database,
+/// }
+///
+/// impl Display for ErrorKind {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             ErrorKind::Database => write!(f, "Database"),
+///         }
+///     }
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement Display for StructError.
+/// implement_error_redacted_display!(StructError);
+///
+/// let err = StructError {
+///     kind: ErrorKind::Database,
+///     message: "cannot connect to postgres://admin:secret@db".to_string(),
+/// };
+/// assert_eq!(err.to_string(), "Database (details hidden)");
+/// assert!(!err.to_string().contains("secret"));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_redacted_display!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Display for StructError {
+///    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///        write!(f, "{} (details hidden)", self.kind.to_string())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_redacted_display {
+    ($struct_error:path) => {
+        impl $crate::__private::fmt::Display for $struct_error {
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter<'_>) -> $crate::__private::fmt::Result {
+                let kind = $crate::__private::ToString::to_string(&self.kind);
+                write!(f, "{} (details hidden)", kind)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, from a [`Cow`] of the error.
+///
+/// Both borrowed and owned errors are accepted, so callers holding a reference don't need to
+/// clone the error. The error type must implement [`Clone`], as required by [`Cow`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_cow!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_cow;
+/// use std::borrow::Cow;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Parse,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<Cow<'_, ParseIntError>> for StructError.
+/// implement_error_from_cow!(StructError, ParseIntError, ErrorKind::Parse);
+///
+/// let parse_error = "x".parse::<u8>().unwrap_err();
+///
+/// let err = StructError::from(Cow::Borrowed(&parse_error));
+/// assert_eq!(err.kind, ErrorKind::Parse);
+/// assert_eq!(err.message, parse_error.to_string());
+///
+/// let err = StructError::from(Cow::<ParseIntError>::Owned(parse_error.clone()));
+/// assert_eq!(err.message, parse_error.to_string());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_cow!(StructError, ParseIntError, ErrorKind::Parse);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<Cow<'_, ParseIntError>> for StructError {
+///    fn from(err: Cow<'_, ParseIntError>) -> Self {
+///        Self {
+///            kind: ErrorKind::Parse,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+///
+/// [`Cow`]: std::borrow::Cow
+#[macro_export]
+macro_rules! implement_error_from_cow {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$crate::__private::Cow<'_, $error_type>> for $struct_error {
+            fn from(error: $crate::__private::Cow<'_, $error_type>) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, with a `severity` attribute
+/// filled with the given value.
+///
+/// The type of the severity is defined by the user.
+///
+/// Expands into [`implement_error_extra!`] with a `severity` attribute.
+///
+/// # Params
+/// ```ignore
+/// implement_error_severity!($struct_error, $error_type, $error_kind, $severity);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_severity;
+/// use std::io;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum Severity {
+///     Warning,
+///     Error,
+/// }
+///
+/// pub enum ErrorKind {
+///     IO,
+///     Parse,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     severity: Severity,
+/// }
+///
+/// implement_error_severity!(StructError, io::Error, ErrorKind::IO, Severity::Error);
+/// implement_error_severity!(StructError, ParseIntError, ErrorKind::Parse, Severity::Warning);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.severity, Severity::Error);
+///
+/// let err = StructError::from("x".parse::<u8>().unwrap_err());
+/// assert_eq!(err.severity, Severity::Warning);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_severity!(StructError, io::Error, ErrorKind::IO, Severity::Error);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            severity: Severity::Error,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_severity {
+    ($struct_error:path, $error_type: path, $error_kind: path, $severity: expr) => {
+        $crate::implement_error_extra!($struct_error, $error_type, $error_kind, { severity: $severity });
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, keeping the error with its
+/// concrete type in the given `Option` attribute.
+///
+/// Unlike [`implement_in_error_in_struct!`], the source is not boxed, so it can be used without
+/// downcasting. The attribute can only hold one error type, so this is meant for errors with a
+/// single kind of source.
+///
+/// # Params
+/// ```ignore
+/// implement_error_typed_source!($struct_error, $error_type, $error_kind, $source_field);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_typed_source;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     io_source: Option<io::Error>,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_typed_source!(StructError, io::Error, ErrorKind::IO, io_source);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// let source = err.io_source.unwrap();
+/// assert_eq!(source.kind(), io::ErrorKind::NotFound);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_typed_source!(StructError, io::Error, ErrorKind::IO, io_source);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            io_source: Some(err),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_typed_source {
+    ($struct_error:path, $error_type: path, $error_kind: path, $source_field: ident) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    $source_field: Some(error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for a tuple struct with message and code positions, flattening
+/// another error with a closure.
+///
+/// The closure receives a reference to the error, and returns the message and the code of the
+/// flat error.
+///
+/// # Params
+/// ```ignore
+/// implement_flatten_error!($flat_error, $error_type, $flatten);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_flatten_error;
+/// use std::fmt::{Display, Formatter};
+///
+/// pub enum EnumError {
+///     NotFound(String),
+/// }
+///
+/// impl EnumError {
+///     pub fn code(&self) -> u32 {
+///         match self {
+///             EnumError::NotFound(_) => 404,
+///         }
+///     }
+/// }
+///
+/// impl Display for EnumError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             EnumError::NotFound(path) => write!(f, "{} not found", path),
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct FlatError(String, u32);
+///
+/// // Implement From<EnumError> for FlatError.
+/// implement_flatten_error!(FlatError, EnumError, |e| (e.to_string(), e.code()));
+///
+/// let err = FlatError::from(EnumError::NotFound("config.toml".to_string()));
+/// assert_eq!(err.0, "config.toml not found");
+/// assert_eq!(err.1, 404);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_flatten_error!(FlatError, EnumError, |e| (e.to_string(), e.code()));
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<EnumError> for FlatError {
+///    fn from(err: EnumError) -> Self {
+///        let flatten = |e: &EnumError| (e.to_string(), e.code());
+///        let (message, code) = flatten(&err);
+///        FlatError(message, code)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_flatten_error {
+    ($flat_error:path, $error_type: path, $flatten: expr) => {
+        impl From<$error_type> for $flat_error {
+            fn from(error: $error_type) -> $flat_error {
+                let flatten = $crate::__private::by_ref::<$error_type, _, _>($flatten);
+                let (message, code) = flatten(&error);
+                $flat_error(message, code)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, from every type implementing
+/// a trait.
+///
+/// The trait must have [`Display`](std::fmt::Display) as supertrait, and be defined in the crate
+/// calling the macro. The struct itself must not implement the trait, otherwise the generated
+/// impl conflicts with the `impl<T> From<T> for T` of the standard library. For the same reason,
+/// other [`From`] implementations for the struct must be from types not implementing the trait.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_trait!($struct_error, dyn $trait_, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_trait;
+/// use std::fmt::{Display, Formatter};
+///
+/// pub trait AppError: Display {}
+///
+/// pub struct ConfigError;
+/// pub struct NetworkError;
+///
+/// impl Display for ConfigError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "invalid configuration")
+///     }
+/// }
+///
+/// impl Display for NetworkError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "network unreachable")
+///     }
+/// }
+///
+/// impl AppError for ConfigError {}
+/// impl AppError for NetworkError {}
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     App,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<T> for StructError, for every T implementing AppError.
+/// implement_error_from_trait!(StructError, dyn AppError, ErrorKind::App);
+///
+/// let err = StructError::from(ConfigError);
+/// assert_eq!(err.kind, ErrorKind::App);
+/// assert_eq!(err.message, "invalid configuration");
+///
+/// let err = StructError::from(NetworkError);
+/// assert_eq!(err.message, "network unreachable");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_trait!(StructError, dyn AppError, ErrorKind::App);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl<T: AppError> From<T> for StructError {
+///    fn from(err: T) -> Self {
+///        Self {
+///            kind: ErrorKind::App,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_from_trait {
+    ($struct_error:path, dyn $trait_: path, $error_kind: path) => {
+        impl<T: $trait_> From<T> for $struct_error {
+            fn from(error: T) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, along with a unit test checking
+/// the conversion.
+///
+/// The `From` implementation is the same as [`implement_error!`]. The test, compiled only with
+/// `cfg(test)`, converts the sample error and asserts that the kind of the struct matches
+/// `$error_kind`, so the kind must be a unit variant.
+///
+/// The sample is any expression building the error, like
+/// `io::Error::from(io::ErrorKind::NotFound)`. Declarative macros cannot build new identifiers,
+/// so the name of the test is given as a param, and must be unique in the module.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_test!($struct_error, $error_type, $error_kind, $test_name, $sample);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_test;
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> for StructError, and test it in `io_error_into_struct_error`.
+/// implement_error_with_test!(
+///     StructError,
+///     io::Error,
+///     ErrorKind::IO,
+///     io_error_into_struct_error,
+///     io::Error::from(io::ErrorKind::NotFound)
+/// );
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert!(matches!(err.kind, ErrorKind::IO));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_test!(
+///     StructError,
+///     io::Error,
+///     ErrorKind::IO,
+///     io_error_into_struct_error,
+///     io::Error::from(io::ErrorKind::NotFound)
+/// );
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+///
+/// #[cfg(test)]
+/// #[test]
+/// fn io_error_into_struct_error() {
+///     let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+///     assert!(matches!(err.kind, ErrorKind::IO));
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_test {
+    ($struct_error:path, $error_type: path, $error_kind: path, $test_name: ident, $sample: expr) => {
+        $crate::implement_error!($struct_error, $error_type, $error_kind);
+
+        #[cfg(test)]
+        #[test]
+        fn $test_name() {
+            let sample: $error_type = $sample;
+            let error: $struct_error = From::from(sample);
+            assert!(matches!(error.kind, $error_kind));
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an error from the never type `!`.
+///
+/// Available with the `nightly` feature. The `!` type is unstable, so the calling crate must be
+/// built with a nightly compiler and enable `#![feature(never_type)]`.
+///
+/// # Params
+/// ```ignore
+/// implement_never!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// #![feature(never_type)]
+///
+/// use heimdall_errors::implement_never;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<!> for StructError.
+/// implement_never!(StructError);
+///
+/// fn never_fails() -> Result<u8, !> {
+///     Ok(42)
+/// }
+///
+/// fn run() -> Result<u8, StructError> {
+///     Ok(never_fails()?)
+/// }
+///
+/// assert_eq!(run().ok(), Some(42));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_never!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<!> for StructError {
+///    fn from(never: !) -> Self {
+///        never
+///     }
+/// }
+/// ```
+#[cfg(feature = "nightly")]
+#[macro_export]
+macro_rules! implement_never {
+    ($struct_error:path) => {
+        impl From<!> for $struct_error {
+            fn from(never: !) -> $struct_error {
+                never
+            }
+        }
+    };
+}
+
+/// Implement the [`Clone`] trait for an struct with kind, message and source attributes.
+///
+/// The kind must implement [`Clone`]. A boxed `dyn Error` can't be cloned, so **the source is
+/// lost**: the clone always has `source: None`, while the original keeps its source. The message
+/// of the source is still available in the cloned `message`.
+///
+/// # Params
+/// ```ignore
+/// implement_error_clone!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error_clone, implement_in_error_in_struct};
+/// use std::io;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error>>,
+/// }
+///
+/// implement_in_error_in_struct!(StructError, io::Error, ErrorKind::IO);
+/// // Implement Clone for StructError.
+/// implement_error_clone!(StructError);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// let clone = err.clone();
+///
+/// assert_eq!(clone.kind, err.kind);
+/// assert_eq!(clone.message, err.message);
+/// assert!(clone.source.is_none());
+/// assert!(err.source.is_some());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_clone!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Clone for StructError {
+///    fn clone(&self) -> Self {
+///        Self {
+///            kind: self.kind.clone(),
+///            message: self.message.clone(),
+///            source: None,
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_clone {
+    ($struct_error:path) => {
+        impl Clone for $struct_error {
+            #[allow(clippy::clone_on_copy)]
+            fn clone(&self) -> Self {
+                Self {
+                    kind: self.kind.clone(),
+                    message: self.message.clone(),
+                    source: None,
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an error, converting through an intermediate type.
+///
+/// [`From`] conversions don't chain, so with `From<A> for B` and `From<B> for StructError`, this
+/// generates `From<A> for StructError` by converting into `B` first.
+///
+/// # Params
+/// ```ignore
+/// implement_error_via!($struct_error, $error_type, $via_type);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error, implement_error_via};
+/// use std::fmt::{Display, Formatter};
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// pub struct ConfigError(String);
+///
+/// impl Display for ConfigError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "invalid configuration: {}", self.0)
+///     }
+/// }
+///
+/// impl From<ParseIntError> for ConfigError {
+///     fn from(err: ParseIntError) -> Self {
+///         ConfigError(err.to_string())
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Config,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// implement_error!(StructError, ConfigError, ErrorKind::Config);
+/// // Implement From<ParseIntError> for StructError, through ConfigError.
+/// implement_error_via!(StructError, ParseIntError, ConfigError);
+///
+/// let err = StructError::from("x".parse::<u8>().unwrap_err());
+/// assert_eq!(err.kind, ErrorKind::Config);
+/// assert_eq!(err.message, "invalid configuration: invalid digit found in string");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_via!(StructError, ParseIntError, ConfigError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<ParseIntError> for StructError {
+///    fn from(err: ParseIntError) -> Self {
+///        StructError::from(ConfigError::from(err))
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_via {
+    ($struct_error:path, $error_type: path, $via_type: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let via: $via_type = From::from(error);
+                From::from(via)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, from a tuple of the error and
+/// a context.
+///
+/// The message has the form `"{context}: {error}"`, so call sites can write
+/// `(err, "loading config").into()`. See [`implement_error_with_context!`] for a context fixed
+/// in the macro call.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_tuple_context!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_tuple_context;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<(io::Error, &'static str)> for StructError.
+/// implement_error_from_tuple_context!(StructError, io::Error, ErrorKind::IO);
+///
+/// fn load() -> Result<String, StructError> {
+///     std::fs::read_to_string("/heimdall/config.toml").map_err(|err| (err, "loading config").into())
+/// }
+///
+/// let err = load().unwrap_err();
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert!(err.message.starts_with("loading config: "));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_tuple_context!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<(io::Error, &'static str)> for StructError {
+///    fn from((err, context): (io::Error, &'static str)) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: format!("{}: {}", context, err),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_from_tuple_context {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<($error_type, &'static str)> for $struct_error {
+            fn from((error, context): ($error_type, &'static str)) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::format!("{}: {}", context, error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for a [tonic] `Status`, from an struct with kind structure.
+///
+/// Available with the `tonic` feature. The code of the status is chosen by matching the kind,
+/// and the message of the status is the message of the struct. Each code is the name of a
+/// variant of `tonic::Code`, and the match must cover every kind.
+///
+/// # Params
+/// ```ignore
+/// implement_tonic_status!($struct_error, { $kind => $code, ... });
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_tonic_status;
+/// use tonic::{Code, Status};
+///
+/// pub enum ErrorKind {
+///     NotFound,
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<StructError> for tonic::Status.
+/// implement_tonic_status!(StructError, {
+///     ErrorKind::NotFound => NotFound,
+///     ErrorKind::IO => Internal,
+/// });
+///
+/// let status = Status::from(StructError { kind: ErrorKind::NotFound, message: "no user".to_string() });
+/// assert_eq!(status.code(), Code::NotFound);
+/// assert_eq!(status.message(), "no user");
+///
+/// let status = Status::from(StructError { kind: ErrorKind::IO, message: "disk full".to_string() });
+/// assert_eq!(status.code(), Code::Internal);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_tonic_status!(StructError, { ErrorKind::NotFound => NotFound, ErrorKind::IO => Internal });
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<StructError> for tonic::Status {
+///    fn from(err: StructError) -> Self {
+///        let code = match &err.kind {
+///            ErrorKind::NotFound => tonic::Code::NotFound,
+///            ErrorKind::IO => tonic::Code::Internal,
+///        };
+///        tonic::Status::new(code, err.message)
+///     }
+/// }
+/// ```
+///
+/// [tonic]:https://crates.io/crates/tonic
+#[cfg(feature = "tonic")]
+#[macro_export]
+macro_rules! implement_tonic_status {
+    ($struct_error:path, { $($kind: pat => $code: ident),* $(,)? }) => {
+        impl From<$struct_error> for $crate::__private::tonic::Status {
+            fn from(error: $struct_error) -> $crate::__private::tonic::Status {
+                let code = match &error.kind {
+                    $($kind => $crate::__private::tonic::Code::$code,)*
+                };
+                $crate::__private::tonic::Status::new(code, error.message)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with an optional kind, with
+/// `kind: Option<ErrorKind>` and message attributes.
+///
+/// The kind is wrapped in [`Some`], unless the last param is `None`, which leaves the error
+/// without kind.
+///
+/// # Params
+/// ```ignore
+/// implement_error_optional_kind!($struct_error, $error_type, $error_kind);
+/// implement_error_optional_kind!($struct_error, $error_type, None);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_optional_kind;
+/// use std::io;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: Option<ErrorKind>,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> and From<ParseIntError> for StructError.
+/// implement_error_optional_kind!(StructError, io::Error, ErrorKind::IO);
+/// implement_error_optional_kind!(StructError, ParseIntError, None);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.kind, Some(ErrorKind::IO));
+///
+/// let err = StructError::from("x".parse::<u8>().unwrap_err());
+/// assert_eq!(err.kind, None);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_optional_kind!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: Some(ErrorKind::IO),
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_optional_kind {
+    ($struct_error:path, $error_type: path, None) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: None,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: Some($error_kind),
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for [String], from an error implementing
+/// [`Display`](std::fmt::Display).
+///
+/// Useful for handlers returning `Result<_, String>`.
+///
+/// # Params
+/// ```ignore
+/// implement_error_into_string!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error, implement_error_display, implement_error_into_string};
+/// use std::env::VarError;
+/// use std::fmt::{Display, Formatter};
+///
+/// pub enum ErrorKind {
+///     Var,
+/// }
+///
+/// impl Display for ErrorKind {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             ErrorKind::Var => write!(f, "Var"),
+///         }
+///     }
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// implement_error!(StructError, VarError, ErrorKind::Var);
+/// implement_error_display!(StructError);
+/// // Implement From<StructError> for String.
+/// implement_error_into_string!(StructError);
+///
+/// fn handler() -> Result<(), String> {
+///     Err(StructError::from(VarError::NotPresent))?
+/// }
+///
+/// assert_eq!(handler().unwrap_err(), "kind: Var message: environment variable not found");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_into_string!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<StructError> for String {
+///    fn from(err: StructError) -> Self {
+///        err.to_string()
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_into_string {
+    ($struct_error:path) => {
+        impl From<$struct_error> for $crate::__private::String {
+            fn from(error: $struct_error) -> $crate::__private::String {
+                $crate::__private::ToString::to_string(&error)
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, running a hook on the error
+/// before building the struct.
+///
+/// The hook receives a reference to the error, and runs synchronously during the conversion. As
+/// it is stored as a function pointer, it can't capture variables; use statics to share state.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_hook!($struct_error, $error_type, $error_kind, $hook);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_hook;
+/// use std::io;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// static CLEANED_UP: AtomicBool = AtomicBool::new(false);
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> for StructError, cleaning up on every conversion.
+/// implement_error_with_hook!(StructError, io::Error, ErrorKind::IO, |_err| {
+///     CLEANED_UP.store(true, Ordering::SeqCst);
+/// });
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert!(CLEANED_UP.load(Ordering::SeqCst));
+/// assert!(matches!(err.kind, ErrorKind::IO));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_hook!(StructError, io::Error, ErrorKind::IO, |_err| cleanup());
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        let hook: fn(&io::Error) = |_err| cleanup();
+///        hook(&err);
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_hook {
+    ($struct_error:path, $error_type: path, $error_kind: path, $hook: expr) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                let hook: fn(&$error_type) = $hook;
+                hook(&error);
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                }
+            }
+        }
+    };
+}
+
+/// Implement a `to_owned_error` method for an struct with kind, message and source attributes,
+/// building an owned copy from a reference.
+///
+/// The kind must implement [`Clone`]. As with [`implement_error_clone!`], the source can't be
+/// copied, so the copy always has `source: None`. The method is not named `to_owned` to avoid
+/// confusion with [`ToOwned`](std::borrow::ToOwned).
+///
+/// # Params
+/// ```ignore
+/// implement_error_to_owned!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error_to_owned, implement_in_error_in_struct};
+/// use std::io;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error>>,
+/// }
+///
+/// implement_in_error_in_struct!(StructError, io::Error, ErrorKind::IO);
+/// // Implement StructError::to_owned_error().
+/// implement_error_to_owned!(StructError);
+///
+/// let prototype = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// let reference: &StructError = &prototype;
+///
+/// let err = reference.to_owned_error();
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert_eq!(err.message, "oh no!");
+/// assert!(err.source.is_none());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_to_owned!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///     pub fn to_owned_error(&self) -> Self {
+///         Self {
+///             kind: self.kind.clone(),
+///             message: self.message.clone(),
+///             source: None,
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_to_owned {
+    ($struct_error:path) => {
+        impl $struct_error {
+            #[allow(clippy::clone_on_copy)]
+            pub fn to_owned_error(&self) -> Self {
+                Self {
+                    kind: self.kind.clone(),
+                    message: self.message.clone(),
+                    source: None,
+                }
+            }
+        }
+    };
+}
+
+/// Implement a `status_code` method for an struct with kind structure, mapping every variant of
+/// the kind to a status code.
+///
+/// Unlike [`implement_http_status!`], the variants are listed by name and the generated match
+/// has no wildcard arm, so adding a variant to the kind fails to compile until it is mapped. The
+/// variants of the kind must be unit variants.
+///
+/// # Params
+/// ```ignore
+/// implement_exhaustive_mapping!($struct_error, $kind_type, { $variant => $status, ... });
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_exhaustive_mapping;
+///
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement StructError::status_code(), for every ErrorKind.
+/// implement_exhaustive_mapping!(StructError, ErrorKind, { IO => 500, Var => 400 });
+///
+/// let err = StructError { kind: ErrorKind::IO, message: String::new() };
+/// assert_eq!(err.status_code(), 500);
+///
+/// let err = StructError { kind: ErrorKind::Var, message: String::new() };
+/// assert_eq!(err.status_code(), 400);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_exhaustive_mapping!(StructError, ErrorKind, { IO => 500, Var => 400 });
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl StructError {
+///     pub fn status_code(&self) -> u16 {
+///         match &self.kind {
+///             ErrorKind::IO => 500,
+///             ErrorKind::Var => 400,
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_exhaustive_mapping {
+    ($struct_error:path, $kind_type:ident, { $($variant: ident => $status: expr),* $(,)? }) => {
+        impl $struct_error {
+            pub fn status_code(&self) -> u16 {
+                match &self.kind {
+                    $($kind_type::$variant => $status,)*
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, for two error types at once.
+///
+/// Each pair is expanded into an [`implement_error!`] invocation. See [`implement_errors!`] for
+/// any number of error types.
+///
+/// # Params
+/// ```ignore
+/// implement_error_pair!($struct_error, ($first_type, $first_kind), ($second_type, $second_kind));
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_pair;
+/// use std::env::VarError;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+///     Var,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> and From<VarError> for StructError.
+/// implement_error_pair!(StructError, (io::Error, ErrorKind::IO), (VarError, ErrorKind::Var));
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.kind, ErrorKind::IO);
+///
+/// let err = StructError::from(VarError::NotPresent);
+/// assert_eq!(err.kind, ErrorKind::Var);
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_pair!(StructError, (io::Error, ErrorKind::IO), (VarError, ErrorKind::Var));
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// implement_error!(StructError, io::Error, ErrorKind::IO);
+/// implement_error!(StructError, VarError, ErrorKind::Var);
+/// ```
+#[macro_export]
+macro_rules! implement_error_pair {
+    ($struct_error:path, ($first_type: path, $first_kind: path), ($second_type: path, $second_kind: path) $(,)?) => {
+        $crate::implement_error!($struct_error, $first_type, $first_kind);
+        $crate::implement_error!($struct_error, $second_type, $second_kind);
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, with a
+/// `request_id: Option<String>` attribute filled by calling a function.
+///
+/// The function takes no arguments and returns an `Option<String>`, usually reading the id of
+/// the current request from a thread-local or task-local value. It is called on every
+/// conversion.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_request_id!($struct_error, $error_type, $error_kind, $request_id);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_with_request_id;
+/// use std::cell::RefCell;
+/// use std::io;
+///
+/// thread_local! {
+///     static REQUEST_ID: RefCell<Option<String>> = RefCell::new(None);
+/// }
+///
+/// fn current_request_id() -> Option<String> {
+///     REQUEST_ID.with(|id| id.borrow().clone())
+/// }
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     request_id: Option<String>,
+/// }
+///
+/// // Implement From<io::Error> for StructError.
+/// implement_error_with_request_id!(StructError, io::Error, ErrorKind::IO, current_request_id);
+///
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.request_id, None);
+///
+/// REQUEST_ID.with(|id| *id.borrow_mut() = Some("req-42".to_string()));
+/// let err = StructError::from(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(err.request_id.as_deref(), Some("req-42"));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_request_id!(StructError, io::Error, ErrorKind::IO, current_request_id);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            request_id: current_request_id(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_request_id {
+    ($struct_error:path, $error_type: path, $error_kind: path, $request_id: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    request_id: $request_id(),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`Display`](std::fmt::Display) trait for an struct with message and source
+/// attributes, without repeating the source when it matches the message.
+///
+/// The message is printed, followed by `": {source}"` only when the source is present and its
+/// [`Display`](std::fmt::Display) output differs from the message. This avoids outputs like
+/// `"oh no!: oh no!"` for errors built with [`implement_in_error_in_struct!`].
+///
+/// # Params
+/// ```ignore
+/// implement_error_dedup_display!($struct_error);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::{implement_error_dedup_display, implement_in_error_in_struct};
+/// use std::io;
+///
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     source: Option<Box<dyn std::error::Error>>,
+/// }
+///
+/// implement_in_error_in_struct!(StructError, io::Error, ErrorKind::IO);
+/// // Implement Display for StructError.
+/// implement_error_dedup_display!(StructError);
+///
+/// let err = StructError::from(io::Error::new(io::ErrorKind::Other, "oh no!"));
+/// assert_eq!(err.to_string(), "oh no!");
+///
+/// let err = StructError {
+///     kind: ErrorKind::IO,
+///     message: "cannot read config".to_string(),
+///     source: Some(Box::new(io::Error::new(io::ErrorKind::Other, "oh no!"))),
+/// };
+/// assert_eq!(err.to_string(), "cannot read config: oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_dedup_display!(StructError);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl Display for StructError {
+///    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///        write!(f, "{}", self.message)?;
+///        if let Some(source) = &self.source {
+///            let source = source.to_string();
+///            if source != self.message {
+///                write!(f, ": {}", source)?;
+///            }
+///        }
+///        Ok(())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_dedup_display {
+    ($struct_error:path) => {
+        impl $crate::__private::fmt::Display for $struct_error {
+            fn fmt(&self, f: &mut $crate::__private::fmt::Formatter<'_>) -> $crate::__private::fmt::Result {
+                write!(f, "{}", self.message)?;
+                if let Some(source) = &self.source {
+                    let source = $crate::__private::ToString::to_string(source);
+                    if source != self.message {
+                        write!(f, ": {}", source)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, from a [nix] error, keeping
+/// its errno in an `errno: i32` attribute.
+///
+/// Available with the `nix` feature. A `nix::Error` is an errno value, so the attribute holds
+/// the raw errno of the failed system call.
+///
+/// # Params
+/// ```ignore
+/// implement_error_from_nix!($struct_error, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_from_nix;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Syscall,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+///     errno: i32,
+/// }
+///
+/// // Implement From<nix::Error> for StructError.
+/// implement_error_from_nix!(StructError, ErrorKind::Syscall);
+///
+/// let err = StructError::from(nix::Error::ENOENT);
+/// assert_eq!(err.kind, ErrorKind::Syscall);
+/// assert_eq!(err.errno, nix::libc::ENOENT);
+/// assert_eq!(err.message, nix::Error::ENOENT.to_string());
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_from_nix!(StructError, ErrorKind::Syscall);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<nix::Error> for StructError {
+///    fn from(err: nix::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::Syscall,
+///            message: err.to_string(),
+///            errno: err as i32,
+///        }
+///     }
+/// }
+/// ```
+///
+/// [nix]:https://crates.io/crates/nix
+#[cfg(feature = "nix")]
+#[macro_export]
+macro_rules! implement_error_from_nix {
+    ($struct_error:path, $error_kind: path) => {
+        impl From<$crate::__private::nix::Error> for $struct_error {
+            fn from(error: $crate::__private::nix::Error) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    errno: error as i32,
+                }
+            }
+        }
+    };
+}
+
+/// Generate a builder accumulating problems into a single enum error with a variant holding
+/// them all.
+///
+/// The builder has a `push(kind, message)` method adding a problem, and a `build()` method
+/// returning `None` when no problem was pushed, or the variant with every `(kind, message)` pair
+/// otherwise. The visibility of the builder is the one given before its name.
+///
+/// # Params
+/// ```ignore
+/// error_builder!($vis $builder, $enum_error, $enum_variant, $kind_type);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::error_builder;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     Missing,
+///     Invalid,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum EnumError {
+///     Multiple(Vec<(ErrorKind, String)>),
+/// }
+///
+/// // Generate ValidationBuilder, building EnumError::Multiple.
+/// error_builder!(pub ValidationBuilder, EnumError, EnumError::Multiple, ErrorKind);
+///
+/// assert_eq!(ValidationBuilder::new().build(), None);
+///
+/// let mut builder = ValidationBuilder::new();
+/// builder
+///     .push(ErrorKind::Missing, "name is required")
+///     .push(ErrorKind::Invalid, format!("age {} is negative", -1));
+///
+/// assert_eq!(
+///     builder.build(),
+///     Some(EnumError::Multiple(vec![
+///         (ErrorKind::Missing, "name is required".to_string()),
+///         (ErrorKind::Invalid, "age -1 is negative".to_string()),
+///     ]))
+/// );
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// error_builder!(pub ValidationBuilder, EnumError, EnumError::Multiple, ErrorKind);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// #[derive(Default)]
+/// pub struct ValidationBuilder {
+///     problems: Vec<(ErrorKind, String)>,
+/// }
+///
+/// impl ValidationBuilder {
+///     pub fn new() -> Self {
+///         Self::default()
+///     }
+///
+///     pub fn push(&mut self, kind: ErrorKind, message: impl Into<String>) -> &mut Self {
+///         self.problems.push((kind, message.into()));
+///         self
+///     }
+///
+///     pub fn build(self) -> Option<EnumError> {
+///         if self.problems.is_empty() {
+///             None
+///         } else {
+///             Some(EnumError::Multiple(self.problems))
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! error_builder {
+    ($vis:vis $builder:ident, $enum_error:ident, $enum_variant: path, $kind_type: ty) => {
+        #[derive(Default)]
+        $vis struct $builder {
+            problems: $crate::__private::Vec<($kind_type, $crate::__private::String)>,
+        }
+
+        impl $builder {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            pub fn push(&mut self, kind: $kind_type, message: impl Into<$crate::__private::String>) -> &mut Self {
+                self.problems.push((kind, message.into()));
+                self
+            }
+
+            pub fn build(self) -> Option<$enum_error> {
+                if self.problems.is_empty() {
+                    None
+                } else {
+                    Some($enum_variant(self.problems))
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, with an
+/// `origin: &'static str` attribute holding the module where the macro is called.
+///
+/// The origin is the [`module_path!`] of the macro call, so the conversions implemented in each
+/// module record which subsystem produced the error.
+///
+/// # Params
+/// ```ignore
+/// implement_error_with_module!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// mod errors {
+///     pub enum ErrorKind {
+///         IO,
+///     }
+///
+///     pub struct StructError {
+///         pub kind: ErrorKind,
+///         pub message: String,
+///         pub origin: &'static str,
+///     }
+///
+///     pub mod storage {
+///         use heimdall_errors::implement_error_with_module;
+///         use std::io;
+///
+///         // Implement From<io::Error> for StructError, with `storage` as origin.
+///         implement_error_with_module!(super::StructError, io::Error, super::ErrorKind::IO);
+///     }
+/// }
+///
+/// let err = errors::StructError::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+/// assert!(err.origin.ends_with("::errors::storage"));
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_with_module!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// impl From<io::Error> for StructError {
+///    fn from(err: io::Error) -> Self {
+///        Self {
+///            kind: ErrorKind::IO,
+///            message: err.to_string(),
+///            origin: module_path!(),
+///        }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! implement_error_with_module {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        impl From<$error_type> for $struct_error {
+            fn from(error: $error_type) -> $struct_error {
+                $struct_error {
+                    kind: $error_kind,
+                    message: $crate::__private::ToString::to_string(&error),
+                    origin: module_path!(),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the [`From`] trait for an struct with kind structure, from both the owned error and
+/// a reference to it.
+///
+/// Expands into [`implement_error!`] and [`implement_error_from_ref!`]. `From<T>` and `From<&T>`
+/// are implemented for different types, so they don't conflict, even when the error type is
+/// [`Copy`]. The owned and borrowed conversions build the same struct.
+///
+/// # Params
+/// ```ignore
+/// implement_error_owned_and_ref!($struct_error, $error_type, $error_kind);
+/// ```
+/// # Example
+/// ```
+/// use heimdall_errors::implement_error_owned_and_ref;
+/// use std::io;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum ErrorKind {
+///     IO,
+/// }
+///
+/// pub struct StructError {
+///     kind: ErrorKind,
+///     message: String,
+/// }
+///
+/// // Implement From<io::Error> and From<&io::Error> for StructError.
+/// implement_error_owned_and_ref!(StructError, io::Error, ErrorKind::IO);
+///
+/// let io_error = io::Error::new(io::ErrorKind::Other, "oh no!");
+///
+/// let err = StructError::from(&io_error);
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert_eq!(err.message, "oh no!");
+///
+/// let err = StructError::from(io_error);
+/// assert_eq!(err.kind, ErrorKind::IO);
+/// assert_eq!(err.message, "oh no!");
+/// ```
+///
+///# Code generated
+/// The code
+/// ```ignore
+/// implement_error_owned_and_ref!(StructError, io::Error, ErrorKind::IO);
+/// ```
+///
+/// generates the next code
+///
+///```ignore
+/// implement_error!(StructError, io::Error, ErrorKind::IO);
+/// implement_error_from_ref!(StructError, io::Error, ErrorKind::IO);
+/// ```
+#[macro_export]
+macro_rules! implement_error_owned_and_ref {
+    ($struct_error:path, $error_type: path, $error_kind: path) => {
+        $crate::implement_error!($struct_error, $error_type, $error_kind);
+        $crate::implement_error_from_ref!($struct_error, $error_type, $error_kind);
+    };
+}
@@ -0,0 +1,43 @@
+use heimdall_errors::implement_error_cfg;
+use std::io;
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    IO,
+    Fallback,
+}
+
+pub struct StructError {
+    kind: ErrorKind,
+    message: String,
+}
+
+// Only implemented when the crate is built with the serde feature.
+implement_error_cfg!(feature = "serde", StructError, io::Error, ErrorKind::IO);
+
+// Would conflict with the macro implementation if the predicate were ignored.
+#[cfg(not(feature = "serde"))]
+impl From<io::Error> for StructError {
+    fn from(_: io::Error) -> Self {
+        Self {
+            kind: ErrorKind::Fallback,
+            message: String::from("fallback"),
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn feature_enabled_uses_macro_implementation() {
+    let err = StructError::from(io::Error::other("oh no!"));
+    assert_eq!(err.kind, ErrorKind::IO);
+    assert_eq!(err.message, "oh no!");
+}
+
+#[test]
+#[cfg(not(feature = "serde"))]
+fn feature_disabled_skips_macro_implementation() {
+    let err = StructError::from(io::Error::other("oh no!"));
+    assert_eq!(err.kind, ErrorKind::Fallback);
+    assert_eq!(err.message, "fallback");
+}
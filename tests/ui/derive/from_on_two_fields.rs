@@ -0,0 +1,16 @@
+use heimdall_errors::FromErrors;
+
+pub enum ErrorKind {
+    IO,
+    Var,
+}
+
+#[derive(FromErrors)]
+pub struct StructError {
+    #[from(std::io::Error => ErrorKind::IO)]
+    pub kind: ErrorKind,
+    #[from(std::env::VarError => ErrorKind::Var)]
+    pub message: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use heimdall_errors::implement_error;
+use std::env::VarError;
+
+pub enum ErrorKind {
+    Var,
+}
+
+pub struct StructError {
+    pub category: ErrorKind,
+    pub message: String,
+}
+
+implement_error!(StructError, VarError, ErrorKind::Var);
+
+fn main() {}
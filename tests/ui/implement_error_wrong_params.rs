@@ -0,0 +1,10 @@
+use heimdall_errors::implement_error;
+
+pub struct StructError {
+    pub kind: (),
+    pub message: String,
+}
+
+implement_error!(StructError, std::env::VarError);
+
+fn main() {}
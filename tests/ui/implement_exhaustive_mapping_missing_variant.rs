@@ -0,0 +1,16 @@
+use heimdall_errors::implement_exhaustive_mapping;
+
+pub enum ErrorKind {
+    IO,
+    Var,
+    Parse,
+}
+
+pub struct StructError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+implement_exhaustive_mapping!(StructError, ErrorKind, { IO => 500, Var => 400 });
+
+fn main() {}
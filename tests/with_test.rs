@@ -0,0 +1,30 @@
+use heimdall_errors::implement_error_with_test;
+use std::env::VarError;
+use std::io;
+
+pub enum ErrorKind {
+    IO,
+    Var,
+}
+
+pub struct StructError {
+    kind: ErrorKind,
+    #[allow(dead_code)]
+    message: String,
+}
+
+implement_error_with_test!(
+    StructError,
+    io::Error,
+    ErrorKind::IO,
+    io_error_into_struct_error,
+    io::Error::from(io::ErrorKind::NotFound)
+);
+
+implement_error_with_test!(
+    StructError,
+    VarError,
+    ErrorKind::Var,
+    var_error_into_struct_error,
+    VarError::NotPresent
+);